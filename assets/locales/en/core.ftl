@@ -4,11 +4,31 @@ open-hint = Open a file ({ $shortcut })
 paste = Paste
 paste-hint = Paste image from clipboard ({ $shortcut })
 
+copy-image = Copy image
+copy-image-hint = Copy the base level to the clipboard as RGBA ({ $shortcut })
+copy-mip-hint = Copy this mip level to the clipboard
+mip-import = Import level…
+mip-import-hint = Replace this mip with a PNG/BLP image of exactly this level's size; BLP export writes it as is
+mip-edited = Imported by hand
+
+open-folder = Open folder
+open-folder-hint = Browse every image in a folder and its subfolders
+
+prev-file-hint = Previous image in this folder (←, PageUp)
+next-file-hint = Next image in this folder (→, PageDown)
+
 pasted-image = Pasted image (clipboard)
 drop-hint = Drag a file here or use { open } / { paste }
+open-list = Opened files
 
 select-image = Select image
 filter-all-images = All images
+select-folder = Select folder
+
+folder-count = Images: { $count }
+folder-close-hint = Close folder view
+folder-show-gallery-hint = Show thumbnail gallery
+folder-show-image-hint = Show the opened image
 
 save-as-blp = Save as BLP
 save-as-png = Save as PNG
@@ -35,6 +55,16 @@ close-error-hint = Close error message
 copy = Copy
 copy-error-hint = Copy error message to clipboard
 
+cancel = Cancel
+cancel-decode-hint = Stop decoding this file (Esc)
+
+progress-read-start = Opening…
+progress-read = Reading { $done } / { $total }
+progress-decode = Decoding…
+progress-decode-blp = Decoding image and mipmaps…
+progress-mip = Building mip { $n } / { $of }
+progress-upload = Uploading textures…
+
 no-image = No image
 
 mips-all = All
@@ -42,6 +72,123 @@ mips-none = None
 hint-mips-all = Enable all mip levels
 hint-mips-none = Disable all mip levels
 
+zoom-fit = Fit
+zoom-100 = 100%
+zoom-200 = 200%
+zoom-400 = 400%
+zoom-nearest = Nearest
+zoom-nearest-hint = Show texels as sharp squares instead of smoothing them. Mouse wheel zooms around the cursor, Shift+wheel scrolls, drag to pan.
+
+channel-rgba-hint = Show the image as is, with transparency
+channel-rgb-hint = Show colour ignoring alpha
+channel-r-hint = Show the red channel as greyscale
+channel-g-hint = Show the green channel as greyscale
+channel-b-hint = Show the blue channel as greyscale
+channel-a-hint = Show the alpha channel as greyscale
+
+backdrop-hint = Backdrop shown behind transparent pixels
+backdrop-checker = Checkerboard
+backdrop-black = Black
+backdrop-white = White
+backdrop-magenta = Magenta
+backdrop-custom = Custom colour
+backdrop-maze = Maze
+backdrop-checker-size-hint = Checkerboard cell size
+
+pinned-pixels = Pinned pixels
+pinned-copy-hint = Copy all pinned pixels as text
+pinned-clear = Clear
+pinned-remove-hint = Click to unpin
+
+info = Info
+info-not-blp = Not a BLP file: no header to show.
+info-version = Version
+info-compression = Compression
+info-alpha-bits = Alpha bits
+info-alpha-type = Alpha type
+info-picture-type = Picture type
+info-has-mips = Has mipmaps
+info-size = Size
+info-jpeg-header = JPEG header
+info-file-size = File size
+info-mips = Mip table
+yes = Yes
+no = No
+
+lint = WC3 compatibility
+lint-ok = No problems found.
+lint-export = On BLP export:
+lint-no-image = No image.
+lint-decode-failed = Failed to decode: { $msg }
+lint-npot = Size { $width }×{ $height } is not a power of two.
+lint-oversized = Size { $width }×{ $height } exceeds { $max } px.
+lint-alpha-all-transparent = Base level is fully transparent.
+lint-not-blp1 = { $version }: Warcraft III expects BLP1.
+lint-compression = Compression { $compression } is not supported by Warcraft III.
+lint-alpha-bits = Alpha depth of { $bits } bits is not supported.
+lint-alpha-unused = { $bits }-bit alpha is stored but the image is fully opaque.
+lint-no-mips = No mipmaps: the texture will shimmer at a distance.
+lint-mips-truncated = Mip chain is truncated: { $present } of { $expected } levels.
+lint-mip-out-of-bounds = Mip #{ $mip } points past the end of the file.
+lint-jpeg-header-missing = JPEG header is missing.
+lint-jpeg-header-large = JPEG header is { $len } bytes (Warcraft III reads at most { $max }).
+lint-jpeg-header-truncated = JPEG header ({ $len } bytes) runs past the end of the file.
+lint-jpeg-header-overlap = JPEG header ({ $len } bytes) overlaps mip data.
+lint-palette-alpha-truncated = Mip #{ $mip }: palette data with alpha is { $size } bytes, { $need } expected.
+lint-export-no-base = Base level is hidden.
+lint-export-mips-gap = Mip #{ $mip } is hidden between visible levels.
+lint-export-mips-truncated = Only { $present } of { $expected } mip levels will be written.
+
+quality-preview = Preview quality
+quality-preview-hint = Re-encode the image in the background at the slider quality and show the result with the estimated .blp size and PSNR.
+quality-preview-encoding = Encoding…
+
+mip-gen = Generate mips
+mip-gen-hint = Rebuild all mip levels from the base level with the chosen filter. The generated levels are shown in the mip list and written on export.
+mip-filter-box = Box
+mip-filter-triangle = Triangle
+mip-filter-lanczos3 = Lanczos3
+mip-filter-kaiser = Kaiser
+mip-gen-gamma = Gamma-correct
+mip-gen-gamma-hint = Average in linear light so contrasting edges do not darken at a distance
+mip-gen-coverage = Preserve alpha coverage
+mip-gen-coverage-hint = Keep the share of pixels above the 50% alpha-test cutoff, so foliage and fences do not thin out at a distance
+error-quality-preview = Failed to build quality preview.
+error-loading-screen = Failed to build loading-screen tiles.
+
+compare = Compare…
+compare-hint = Open a second image (B) to compare with the current one
+compare-side-by-side = Side by side
+compare-swipe = Swipe
+compare-flicker = Flicker
+compare-heatmap = Heatmap
+compare-swipe-hint = Swipe position: A on the left, B on the right
+compare-close-hint = Close comparison
+compare-metrics = A/B difference
+compare-copy-hint = Copy per-mip metrics as text
+error-compare-load = Failed to open image for comparison: { $path }
+
+batch = Batch conversion
+batch-open = Batch…
+batch-open-hint = Convert many files or whole folders to BLP or PNG
+batch-add-files = Add files…
+batch-add-folder = Add folder…
+batch-clear = Clear
+batch-mips-limit = Mip levels
+batch-same-dir = Next to source
+batch-choose-dir = Output folder…
+batch-start = Start
+batch-cancel = Cancel
+batch-cancel-hint = Skip files that have not started yet; files in progress will finish
+batch-export-report = Export report…
+batch-queued = Queued
+batch-decoding = Decoding…
+batch-encoding = Encoding…
+batch-cancelled = Cancelled
+error-batch-same-file = Output would overwrite the source: { $path }
+error-batch-duplicate-output = Another file in the batch is already written to: { $path }
+error-batch-worker-lost = Conversion stopped unexpectedly: { $path }
+
 blp-quality = BLP quality: { $val }
 blp-quality-hint = Compression quality for BLP export. 0 = worst, 100 = best.
 
@@ -49,8 +196,44 @@ no-visible-mip-hint = No mip level is selected.\nUse the right panel to choose o
 
 blp-texture = BLP texture
 png-image = PNG image
+png-mode-base = PNG: base level
+png-mode-mips = PNG: each visible mip
+png-mode-strip = PNG: mips as one strip
+png-mode-hint = What to write on PNG export: the base level only, every visible mip as name_mipNN.png, or all visible mips stacked vertically in one image.
+
+icon-set = Icon set…
+icon-set-hint = Write the Warcraft III command-card icons (64×64, framed; DIS* desaturated and darkened) as BLP at the current quality:
+
+loading-screen = Loading screen
+loading-screen-open = Loading screen…
+loading-screen-open-hint = Cut the image into Warcraft III loading-screen tiles with a preview of the reassembled result
+loading-screen-layout = Fitted into { $w }×{ $h } without distortion (black bars fill the rest) and cut into { $tile }×{ $tile } BLP tiles. The preview is assembled from the decoded tiles.
+loading-screen-encoding = Encoding tiles…
+loading-screen-export = Export tiles…
+loading-screen-export-hint = Write the BLP tiles and a .txt manifest with the UV layout and the MDL Textures block
+
+pot-npot-source = Source is not a power of two
+pot-resize = Resize to power of two on BLP export
+pot-resize-hint = Warcraft III expects power-of-two textures. The mip chain is rebuilt from the resized base level.
+pot-resize-edited = Unavailable while mip levels are imported by hand: the rebuilt chain would drop them.
+pot-target-larger = Nearest larger
+pot-target-smaller = Nearest smaller
+pot-target-explicit = Explicit size
+pot-method-lanczos3 = Lanczos3
+pot-method-bilinear = Bilinear
+pot-method-nearest = Nearest
+pot-method-pad = Pad with transparency
+pot-preview = Before / after
+pot-preview-hint = Compare the source with the image that will be written
+pot-preview-none = The image already has the target size.
+pot-before = Before
+pot-after = After
 
 error-save-no-image = No image to save
+error-save-blp = Failed to encode BLP: { $path }
+error-io-write-file = Failed to write file: { $path } ({ $msg })
+error-save-png = Failed to write PNG: { $path }
+error-save-no-visible-mip = No visible mip level to save
 
 error-poll-decoder = Internal decoder error.
 
@@ -72,3 +255,7 @@ error-clipboard-init-failed = Failed to access clipboard.
 error-clipboard-no-image = No image found in clipboard.
 error-clipboard-invalid-buffer = Invalid clipboard image buffer (width={ $width }, height={ $height }).
 error-clipboard-encode-png-failed = Failed to encode PNG from clipboard image.
+error-clipboard-copy-failed = Failed to copy the image to the clipboard.
+error-copy-no-image = This mip level is not loaded.
+error-mip-import-size = Mip { $n } must be { $expected }, the image is { $actual }.
+error-mip-import-index = Mip { $n } does not exist: the base level has only { $count } levels.
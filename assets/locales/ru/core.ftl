@@ -4,11 +4,31 @@ open-hint = Открыть файл ({ $shortcut })
 paste = Вставить
 paste-hint = Вставить изображение из буфера обмена ({ $shortcut })
 
+copy-image = Копировать
+copy-image-hint = Скопировать базовый уровень в буфер обмена как RGBA ({ $shortcut })
+copy-mip-hint = Скопировать этот мип в буфер обмена
+mip-import = Импорт уровня…
+mip-import-hint = Заменить этот мип изображением PNG/BLP ровно такого же размера; экспорт в BLP запишет его как есть
+mip-edited = Импортирован вручную
+
+open-folder = Открыть папку
+open-folder-hint = Просмотреть все изображения в папке и её подпапках
+
+prev-file-hint = Предыдущее изображение в этой папке (←, PageUp)
+next-file-hint = Следующее изображение в этой папке (→, PageDown)
+
 pasted-image = Вставленное изображение (буфер обмена)
 drop-hint = Перетащите файл сюда или используйте { open } / { paste }
+open-list = Открытые файлы
 
 select-image = Выбрать изображение
 filter-all-images = Все изображения
+select-folder = Выбрать папку
+
+folder-count = Изображений: { $count }
+folder-close-hint = Закрыть просмотр папки
+folder-show-gallery-hint = Показать галерею миниатюр
+folder-show-image-hint = Показать открытое изображение
 
 save-as-blp = Сохранить как BLP
 save-as-png = Сохранить как PNG
@@ -35,6 +55,16 @@ close-error-hint = Закрыть сообщение об ошибке
 copy = Копировать
 copy-error-hint = Скопировать сообщение об ошибке в буфер обмена
 
+cancel = Отмена
+cancel-decode-hint = Прервать декодирование файла (Esc)
+
+progress-read-start = Открытие…
+progress-read = Чтение { $done } / { $total }
+progress-decode = Декодирование…
+progress-decode-blp = Декодирование изображения и мипмапов…
+progress-mip = Мип { $n } / { $of }
+progress-upload = Загрузка текстур…
+
 no-image = Нет изображения
 
 mips-all = Все
@@ -42,6 +72,123 @@ mips-none = Никто
 hint-mips-all = Включить все уровни мипов
 hint-mips-none = Отключить все уровни мипов
 
+zoom-fit = Вписать
+zoom-100 = 100%
+zoom-200 = 200%
+zoom-400 = 400%
+zoom-nearest = Пиксели
+zoom-nearest-hint = Показывать тексели чёткими квадратами без сглаживания. Колесо мыши — масштаб вокруг курсора, Shift+колесо — прокрутка, перетаскивание — сдвиг.
+
+channel-rgba-hint = Показать изображение как есть, с прозрачностью
+channel-rgb-hint = Показать цвет без учёта альфы
+channel-r-hint = Показать красный канал оттенками серого
+channel-g-hint = Показать зелёный канал оттенками серого
+channel-b-hint = Показать синий канал оттенками серого
+channel-a-hint = Показать альфа-канал оттенками серого
+
+backdrop-hint = Подложка под прозрачными пикселями
+backdrop-checker = Шахматка
+backdrop-black = Чёрный
+backdrop-white = Белый
+backdrop-magenta = Пурпурный
+backdrop-custom = Свой цвет
+backdrop-maze = Лабиринт
+backdrop-checker-size-hint = Размер клетки шахматки
+
+pinned-pixels = Закреплённые пиксели
+pinned-copy-hint = Скопировать все закреплённые пиксели текстом
+pinned-clear = Очистить
+pinned-remove-hint = Нажмите, чтобы открепить
+
+info = Информация
+info-not-blp = Не BLP: заголовка нет.
+info-version = Версия
+info-compression = Сжатие
+info-alpha-bits = Биты альфы
+info-alpha-type = Тип альфы
+info-picture-type = Тип изображения
+info-has-mips = Есть мипмапы
+info-size = Размер
+info-jpeg-header = JPEG-заголовок
+info-file-size = Размер файла
+info-mips = Таблица мипов
+yes = Да
+no = Нет
+
+lint = Совместимость с WC3
+lint-ok = Проблем не найдено.
+lint-export = При экспорте в BLP:
+lint-no-image = Нет изображения.
+lint-decode-failed = Не удалось декодировать: { $msg }
+lint-npot = Размер { $width }×{ $height } — не степень двойки.
+lint-oversized = Размер { $width }×{ $height } больше { $max } px.
+lint-alpha-all-transparent = Базовый уровень полностью прозрачен.
+lint-not-blp1 = { $version }: Warcraft III ожидает BLP1.
+lint-compression = Сжатие { $compression } не поддерживается Warcraft III.
+lint-alpha-bits = Глубина альфы { $bits } бит не поддерживается.
+lint-alpha-unused = Хранится { $bits }-битная альфа, но изображение полностью непрозрачно.
+lint-no-mips = Нет мипмапов: текстура будет рябить на расстоянии.
+lint-mips-truncated = Цепочка мипов обрезана: { $present } из { $expected } уровней.
+lint-mip-out-of-bounds = Мип #{ $mip } указывает за конец файла.
+lint-jpeg-header-missing = Нет JPEG-заголовка.
+lint-jpeg-header-large = JPEG-заголовок { $len } байт (Warcraft III читает не больше { $max }).
+lint-jpeg-header-truncated = JPEG-заголовок ({ $len } байт) выходит за конец файла.
+lint-jpeg-header-overlap = JPEG-заголовок ({ $len } байт) налезает на данные мипов.
+lint-palette-alpha-truncated = Мип #{ $mip }: данные палитры с альфой — { $size } байт, ожидалось { $need }.
+lint-export-no-base = Базовый уровень скрыт.
+lint-export-mips-gap = Мип #{ $mip } скрыт между видимыми уровнями.
+lint-export-mips-truncated = Будет записано только { $present } из { $expected } уровней.
+
+quality-preview = Превью качества
+quality-preview-hint = Перекодировать изображение в фоне с качеством со слайдера и показать результат, оценку размера .blp и PSNR.
+quality-preview-encoding = Кодирование…
+
+mip-gen = Генерировать мипы
+mip-gen-hint = Пересобрать все уровни из базового выбранным фильтром. Сгенерированные уровни видны в списке мипов и записываются при экспорте.
+mip-filter-box = Box
+mip-filter-triangle = Треугольный
+mip-filter-lanczos3 = Lanczos3
+mip-filter-kaiser = Кайзер
+mip-gen-gamma = С учётом гаммы
+mip-gen-gamma-hint = Усреднять в линейном свете, чтобы контрастные края не темнели вдали
+mip-gen-coverage = Сохранять покрытие альфы
+mip-gen-coverage-hint = Сохранять долю пикселей выше порога альфа-теста 50%, чтобы листва и заборы не редели вдали
+error-quality-preview = Не удалось построить превью качества.
+error-loading-screen = Не удалось нарезать тайлы экрана загрузки.
+
+compare = Сравнить…
+compare-hint = Открыть вторую картинку (B) для сравнения с текущей
+compare-side-by-side = Рядом
+compare-swipe = Шторка
+compare-flicker = Мерцание
+compare-heatmap = Тепловая карта
+compare-swipe-hint = Положение шторки: слева A, справа B
+compare-close-hint = Закрыть сравнение
+compare-metrics = Разница A/B
+compare-copy-hint = Скопировать метрики по мипам текстом
+error-compare-load = Не удалось открыть картинку для сравнения: { $path }
+
+batch = Пакетная конвертация
+batch-open = Пакет…
+batch-open-hint = Конвертировать много файлов или целые папки в BLP или PNG
+batch-add-files = Добавить файлы…
+batch-add-folder = Добавить папку…
+batch-clear = Очистить
+batch-mips-limit = Уровни мипов
+batch-same-dir = Рядом с исходником
+batch-choose-dir = Папка вывода…
+batch-start = Запустить
+batch-cancel = Отменить
+batch-cancel-hint = Пропустить ещё не начатые файлы; начатые будут доделаны
+batch-export-report = Экспорт отчёта…
+batch-queued = В очереди
+batch-decoding = Декодирование…
+batch-encoding = Кодирование…
+batch-cancelled = Отменено
+error-batch-same-file = Результат перезаписал бы исходник: { $path }
+error-batch-duplicate-output = В этот файл уже пишет другой файл пачки: { $path }
+error-batch-worker-lost = Конвертация неожиданно прервалась: { $path }
+
 blp-quality = Качество BLP: { $val }
 blp-quality-hint = Качество сжатия при экспорте BLP. 0 — худшее, 100 — лучшее.
 
@@ -49,8 +196,44 @@ no-visible-mip-hint = Ни один мип не выбран.\nИспользу
 
 blp-texture = BLP текстура
 png-image = PNG изображение
+png-mode-base = PNG: базовый уровень
+png-mode-mips = PNG: каждый видимый мип
+png-mode-strip = PNG: мипы одной полосой
+png-mode-hint = Что записывать при экспорте в PNG: только базовый уровень, каждый видимый мип как name_mipNN.png или все видимые мипы одной вертикальной полосой.
+
+icon-set = Набор иконок…
+icon-set-hint = Записать иконки командной карты Warcraft III (64×64, с рамкой; DIS* обесцвечены и затемнены) в BLP с текущим качеством:
+
+loading-screen = Экран загрузки
+loading-screen-open = Экран загрузки…
+loading-screen-open-hint = Нарезать изображение на тайлы экрана загрузки Warcraft III с превью собранного результата
+loading-screen-layout = Вписано в { $w }×{ $h } без искажения пропорций (остальное — чёрные поля) и нарезано на тайлы BLP { $tile }×{ $tile }. Превью собрано из декодированных тайлов.
+loading-screen-encoding = Кодирование тайлов…
+loading-screen-export = Экспорт тайлов…
+loading-screen-export-hint = Записать тайлы BLP и манифест .txt с раскладкой UV и блоком Textures для MDL
+
+pot-npot-source = Размер исходника — не степень двойки
+pot-resize = Привести к степени двойки при экспорте в BLP
+pot-resize-hint = Warcraft III ожидает текстуры со сторонами степени двойки. Цепочка мипов строится заново из изменённого базового уровня.
+pot-resize-edited = Недоступно, пока есть вручную импортированные мипы: новая цепочка их бы потеряла.
+pot-target-larger = Ближайший больший
+pot-target-smaller = Ближайший меньший
+pot-target-explicit = Заданный размер
+pot-method-lanczos3 = Lanczos3
+pot-method-bilinear = Билинейный
+pot-method-nearest = Ближайший сосед
+pot-method-pad = Дополнить прозрачностью
+pot-preview = До / после
+pot-preview-hint = Сравнить исходник с изображением, которое будет записано
+pot-preview-none = Изображение уже нужного размера.
+pot-before = До
+pot-after = После
 
 error-save-no-image = Нет изображения для сохранения
+error-save-blp = Не удалось закодировать BLP: { $path }
+error-io-write-file = Не удалось записать файл: { $path } ({ $msg })
+error-save-png = Не удалось записать PNG: { $path }
+error-save-no-visible-mip = Нет видимых мип-уровней для сохранения
 
 error-poll-decoder = Внутренняя ошибка декодера.
 
@@ -72,3 +255,7 @@ error-clipboard-init-failed = Не удалось получить доступ
 error-clipboard-no-image = В буфере обмена нет изображения.
 error-clipboard-invalid-buffer = Некорректный буфер изображения (ширина={ $width }, высота={ $height }).
 error-clipboard-encode-png-failed = Не удалось закодировать PNG из изображения буфера обмена.
+error-clipboard-copy-failed = Не удалось скопировать изображение в буфер обмена.
+error-copy-no-image = Этот мип не загружен.
+error-mip-import-size = Мип { $n } должен быть { $expected }, а изображение — { $actual }.
+error-mip-import-index = Мипа { $n } нет: у базового уровня всего { $count } уровней.
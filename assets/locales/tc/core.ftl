@@ -4,11 +4,31 @@ open-hint = 開啟一個檔案 ({ $shortcut })
 paste = 貼上
 paste-hint = 從剪貼簿貼上影像 ({ $shortcut })
 
+copy-image = 複製影像
+copy-image-hint = 將基礎層級以 RGBA 複製到剪貼簿 ({ $shortcut })
+copy-mip-hint = 將此 Mip 層級複製到剪貼簿
+mip-import = 匯入層級…
+mip-import-hint = 以尺寸與該層級完全一致的 PNG/BLP 影像取代此 Mip；匯出 BLP 時原樣寫入
+mip-edited = 手動匯入
+
+open-folder = 開啟資料夾
+open-folder-hint = 瀏覽資料夾及其子資料夾中的所有影像
+
+prev-file-hint = 此資料夾中的上一張影像（←，PageUp）
+next-file-hint = 此資料夾中的下一張影像（→，PageDown）
+
 pasted-image = 貼上的影像 (剪貼簿)
 drop-hint = 拖動檔案到這裡或使用 { open } / { paste }
+open-list = 已開啟的檔案
 
 select-image = 選擇影像
 filter-all-images = 所有影像
+select-folder = 選擇資料夾
+
+folder-count = 影像：{ $count }
+folder-close-hint = 關閉資料夾檢視
+folder-show-gallery-hint = 顯示縮圖庫
+folder-show-image-hint = 顯示已開啟的影像
 
 save-as-blp = 另存為 BLP
 save-as-png = 另存為 PNG
@@ -35,20 +55,183 @@ close-error-hint = 關閉錯誤訊息
 copy = 複製
 copy-error-hint = 複製錯誤訊息到剪貼簿
 
+cancel = 取消
+cancel-decode-hint = 停止解碼此檔案 (Esc)
+
+progress-read-start = 正在開啟…
+progress-read = 正在讀取 { $done } / { $total }
+progress-decode = 正在解碼…
+progress-decode-blp = 正在解碼影像和 Mipmap…
+progress-mip = 正在產生 Mip { $n } / { $of }
+progress-upload = 正在上傳紋理…
+
 no-image = 無影像
 
 mips-all = 全部
 mips-none = 無
 hint-mips-all = 啟用所有 mip 級別
 hint-mips-none = 停用所有 mip 級別
+
+zoom-fit = 適應
+zoom-100 = 100%
+zoom-200 = 200%
+zoom-400 = 400%
+zoom-nearest = 像素
+zoom-nearest-hint = 以清晰方塊顯示紋素而不平滑。滑鼠滾輪以游標為中心縮放，Shift+滾輪捲動，拖曳以平移。
+
+channel-rgba-hint = 按原樣顯示影像（含透明度）
+channel-rgb-hint = 顯示顏色，忽略 Alpha
+channel-r-hint = 以灰階顯示紅色通道
+channel-g-hint = 以灰階顯示綠色通道
+channel-b-hint = 以灰階顯示藍色通道
+channel-a-hint = 以灰階顯示 Alpha 通道
+
+backdrop-hint = 透明像素後顯示的背景
+backdrop-checker = 棋盤格
+backdrop-black = 黑色
+backdrop-white = 白色
+backdrop-magenta = 洋紅
+backdrop-custom = 自訂顏色
+backdrop-maze = 迷宮
+backdrop-checker-size-hint = 棋盤格單元大小
+
+pinned-pixels = 已固定的像素
+pinned-copy-hint = 將所有已固定的像素複製為文字
+pinned-clear = 清除
+pinned-remove-hint = 點擊取消固定
+
+info = 資訊
+info-not-blp = 不是 BLP 檔案：沒有可顯示的檔頭。
+info-version = 版本
+info-compression = 壓縮
+info-alpha-bits = Alpha 位元數
+info-alpha-type = Alpha 類型
+info-picture-type = 圖片類型
+info-has-mips = 含 Mipmap
+info-size = 尺寸
+info-jpeg-header = JPEG 檔頭
+info-file-size = 檔案大小
+info-mips = Mip 表
+yes = 是
+no = 否
+
+lint = WC3 相容性
+lint-ok = 未發現問題。
+lint-export = 匯出為 BLP 時：
+lint-no-image = 沒有影像。
+lint-decode-failed = 解碼失敗：{ $msg }
+lint-npot = 尺寸 { $width }×{ $height } 不是 2 的冪。
+lint-oversized = 尺寸 { $width }×{ $height } 超過 { $max } 像素。
+lint-alpha-all-transparent = 基礎層完全透明。
+lint-not-blp1 = { $version }：魔獸爭霸 III 需要 BLP1。
+lint-compression = 魔獸爭霸 III 不支援 { $compression } 壓縮。
+lint-alpha-bits = 不支援 { $bits } 位元 Alpha。
+lint-alpha-unused = 儲存了 { $bits } 位元 Alpha，但影像完全不透明。
+lint-no-mips = 沒有 Mipmap：遠處紋理會閃爍。
+lint-mips-truncated = Mip 鏈不完整：{ $present } / { $expected } 級。
+lint-mip-out-of-bounds = Mip #{ $mip } 指向檔案結尾之外。
+lint-jpeg-header-missing = 缺少 JPEG 檔頭。
+lint-jpeg-header-large = JPEG 檔頭為 { $len } 位元組（魔獸爭霸 III 最多讀取 { $max }）。
+lint-jpeg-header-truncated = JPEG 檔頭（{ $len } 位元組）超出檔案結尾。
+lint-jpeg-header-overlap = JPEG 檔頭（{ $len } 位元組）與 Mip 資料重疊。
+lint-palette-alpha-truncated = Mip #{ $mip }：帶 Alpha 的調色盤資料為 { $size } 位元組，應為 { $need }。
+lint-export-no-base = 基礎層已隱藏。
+lint-export-mips-gap = Mip #{ $mip } 在可見層之間被隱藏。
+lint-export-mips-truncated = 只會寫入 { $present } / { $expected } 級 Mip。
+
+quality-preview = 品質預覽
+quality-preview-hint = 在背景依滑桿品質重新編碼影像，顯示結果、預估 .blp 大小與 PSNR。
+quality-preview-encoding = 編碼中…
+
+mip-gen = 產生 Mip
+mip-gen-hint = 使用所選濾鏡從基礎層級重建全部 Mip 層級。產生的層級會顯示在 Mip 清單中並在匯出時寫入。
+mip-filter-box = 盒式
+mip-filter-triangle = 三角
+mip-filter-lanczos3 = Lanczos3
+mip-filter-kaiser = Kaiser
+mip-gen-gamma = 伽瑪校正
+mip-gen-gamma-hint = 在線性光空間中求平均，避免高對比邊緣在遠處變暗
+mip-gen-coverage = 保持 Alpha 覆蓋率
+mip-gen-coverage-hint = 保持高於 50% Alpha 測試閾值的像素比例，使樹葉和柵欄在遠處不會變稀
+error-quality-preview = 無法產生品質預覽。
+error-loading-screen = 無法產生載入畫面圖塊。
+
+compare = 比較…
+compare-hint = 開啟第二張影像 (B) 與目前影像比較
+compare-side-by-side = 並排
+compare-swipe = 滑動
+compare-flicker = 閃爍
+compare-heatmap = 熱度圖
+compare-swipe-hint = 滑桿位置：左側 A，右側 B
+compare-close-hint = 關閉比較
+compare-metrics = A/B 差異
+compare-copy-hint = 將各級 Mip 指標複製為文字
+error-compare-load = 無法開啟用於比較的影像：{ $path }
+
+batch = 批次轉換
+batch-open = 批次…
+batch-open-hint = 將多個檔案或整個資料夾轉換為 BLP 或 PNG
+batch-add-files = 新增檔案…
+batch-add-folder = 新增資料夾…
+batch-clear = 清除
+batch-mips-limit = Mip 級數
+batch-same-dir = 與來源同目錄
+batch-choose-dir = 輸出資料夾…
+batch-start = 開始
+batch-cancel = 取消
+batch-cancel-hint = 略過尚未開始的檔案；進行中的檔案會完成
+batch-export-report = 匯出報告…
+batch-queued = 佇列中
+batch-decoding = 解碼中…
+batch-encoding = 編碼中…
+batch-cancelled = 已取消
+error-batch-same-file = 輸出會覆寫來源檔案：{ $path }
+error-batch-duplicate-output = 批次中的另一個檔案已寫入：{ $path }
+error-batch-worker-lost = 轉換意外中止：{ $path }
 blp-quality = BLP 匯出品質
 blp-quality-hint = 匯出 BLP 時使用的 JPEG 品質
 no-visible-mip-hint = 目前沒有選擇任何 mip 級別。請使用右側面板進行選擇。
 
 blp-texture = BLP 紋理
 png-image = PNG 影像
+png-mode-base = PNG：基礎層級
+png-mode-mips = PNG：每個可見 mip
+png-mode-strip = PNG：mip 拼成一條
+png-mode-hint = PNG 匯出內容：僅基礎層級、每個可見 mip 單獨儲存為 name_mipNN.png，或將所有可見 mip 直向拼接為一張圖。
+
+icon-set = 圖示套組…
+icon-set-hint = 依目前品質將魔獸爭霸 III 指令卡圖示（64×64，含邊框；DIS* 去色並變暗）寫為 BLP：
+
+loading-screen = 載入畫面
+loading-screen-open = 載入畫面…
+loading-screen-open-hint = 將影像切分為魔獸爭霸 III 載入畫面圖塊，並預覽拼合結果
+loading-screen-layout = 按原比例縮放至 { $w }×{ $h }（其餘部分填充黑邊）並切分為 { $tile }×{ $tile } 的 BLP 圖塊。預覽由解碼後的圖塊拼合而成。
+loading-screen-encoding = 正在編碼圖塊…
+loading-screen-export = 匯出圖塊…
+loading-screen-export-hint = 寫入 BLP 圖塊以及包含 UV 配置與 MDL Textures 區塊的 .txt 清單
+
+pot-npot-source = 來源尺寸不是 2 的冪
+pot-resize = 匯出 BLP 時調整為 2 的冪
+pot-resize-hint = 魔獸爭霸 III 需要邊長為 2 的冪的紋理。Mip 鏈將從調整後的基礎層級重新產生。
+pot-resize-edited = 存在手動匯入的 Mip 時無法使用：重建的 Mip 鏈會捨棄它們。
+pot-target-larger = 向上取最近值
+pot-target-smaller = 向下取最近值
+pot-target-explicit = 指定尺寸
+pot-method-lanczos3 = Lanczos3
+pot-method-bilinear = 雙線性
+pot-method-nearest = 最近鄰
+pot-method-pad = 以透明像素填補
+pot-preview = 前 / 後對比
+pot-preview-hint = 將來源與即將寫入的影像進行對比
+pot-preview-none = 影像已是目標尺寸。
+pot-before = 之前
+pot-after = 之後
 
 error-save-no-image = 沒有可儲存的影像
+error-save-blp = 無法編碼 BLP：{ $path }
+error-io-write-file = 無法寫入檔案：{ $path }（{ $msg }）
+error-save-png = 無法寫入 PNG：{ $path }
+error-save-no-visible-mip = 沒有可儲存的可見 mip 層級
 
 error-poll-decoder = 解碼器內部錯誤。
 
@@ -70,3 +253,7 @@ error-clipboard-init-failed = 無法訪問剪貼簿。
 error-clipboard-no-image = 剪貼簿中未找到影像。
 error-clipboard-invalid-buffer = 剪貼簿影像緩衝區無效 (寬度={ $width }, 高度={ $height })。
 error-clipboard-encode-png-failed = 從剪貼簿影像編碼 PNG 失敗。
+error-clipboard-copy-failed = 複製影像到剪貼簿失敗。
+error-copy-no-image = 此 Mip 層級未載入。
+error-mip-import-size = Mip { $n } 應為 { $expected }，但影像為 { $actual }。
+error-mip-import-index = Mip { $n } 不存在：基礎層級只有 { $count } 個層級。
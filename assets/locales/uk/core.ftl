@@ -4,11 +4,31 @@ open-hint = Відкрити файл ({ $shortcut })
 paste = Вставити
 paste-hint = Вставити зображення з буфера обміну ({ $shortcut })
 
+copy-image = Копіювати
+copy-image-hint = Скопіювати базовий рівень у буфер обміну як RGBA ({ $shortcut })
+copy-mip-hint = Скопіювати цей міп у буфер обміну
+mip-import = Імпорт рівня…
+mip-import-hint = Замінити цей міп зображенням PNG/BLP точно такого ж розміру; експорт у BLP запише його як є
+mip-edited = Імпортовано вручну
+
+open-folder = Відкрити теку
+open-folder-hint = Переглянути всі зображення в теці та її підтеках
+
+prev-file-hint = Попереднє зображення в цій теці (←, PageUp)
+next-file-hint = Наступне зображення в цій теці (→, PageDown)
+
 pasted-image = Вставлене зображення (буфер обміну)
 drop-hint = Перетягніть файл сюди або скористайтеся { open } / { paste }
+open-list = Відкриті файли
 
 select-image = Вибрати зображення
 filter-all-images = Усі зображення
+select-folder = Вибрати теку
+
+folder-count = Зображень: { $count }
+folder-close-hint = Закрити перегляд теки
+folder-show-gallery-hint = Показати галерею мініатюр
+folder-show-image-hint = Показати відкрите зображення
 
 save-as-blp = Зберегти як BLP
 save-as-png = Зберегти як PNG
@@ -35,6 +55,16 @@ close-error-hint = Закрити повідомлення про помилку
 copy = Копіювати
 copy-error-hint = Скопіювати повідомлення про помилку в буфер обміну
 
+cancel = Скасувати
+cancel-decode-hint = Перервати декодування файлу (Esc)
+
+progress-read-start = Відкриття…
+progress-read = Читання { $done } / { $total }
+progress-decode = Декодування…
+progress-decode-blp = Декодування зображення та мипмапів…
+progress-mip = Міп { $n } / { $of }
+progress-upload = Завантаження текстур…
+
 no-image = Немає зображення
 
 mips-all = Усі
@@ -42,6 +72,123 @@ mips-none = Жоден
 hint-mips-all = Увімкнути всі рівні міпів
 hint-mips-none = Вимкнути всі рівні міпів
 
+zoom-fit = Вписати
+zoom-100 = 100%
+zoom-200 = 200%
+zoom-400 = 400%
+zoom-nearest = Пікселі
+zoom-nearest-hint = Показувати текселі чіткими квадратами без згладжування. Коліщатко миші — масштаб довкола курсора, Shift+коліщатко — прокручування, перетягування — зсув.
+
+channel-rgba-hint = Показати зображення як є, з прозорістю
+channel-rgb-hint = Показати колір без урахування альфи
+channel-r-hint = Показати червоний канал відтінками сірого
+channel-g-hint = Показати зелений канал відтінками сірого
+channel-b-hint = Показати синій канал відтінками сірого
+channel-a-hint = Показати альфа-канал відтінками сірого
+
+backdrop-hint = Підкладка під прозорими пікселями
+backdrop-checker = Шахівниця
+backdrop-black = Чорний
+backdrop-white = Білий
+backdrop-magenta = Пурпуровий
+backdrop-custom = Власний колір
+backdrop-maze = Лабіринт
+backdrop-checker-size-hint = Розмір клітинки шахівниці
+
+pinned-pixels = Закріплені пікселі
+pinned-copy-hint = Скопіювати всі закріплені пікселі текстом
+pinned-clear = Очистити
+pinned-remove-hint = Натисніть, щоб відкріпити
+
+info = Інформація
+info-not-blp = Не BLP: заголовка немає.
+info-version = Версія
+info-compression = Стиснення
+info-alpha-bits = Біти альфи
+info-alpha-type = Тип альфи
+info-picture-type = Тип зображення
+info-has-mips = Є мипмапи
+info-size = Розмір
+info-jpeg-header = JPEG-заголовок
+info-file-size = Розмір файлу
+info-mips = Таблиця мипів
+yes = Так
+no = Ні
+
+lint = Сумісність з WC3
+lint-ok = Проблем не знайдено.
+lint-export = Під час експорту в BLP:
+lint-no-image = Немає зображення.
+lint-decode-failed = Не вдалося декодувати: { $msg }
+lint-npot = Розмір { $width }×{ $height } — не степінь двійки.
+lint-oversized = Розмір { $width }×{ $height } більший за { $max } px.
+lint-alpha-all-transparent = Базовий рівень повністю прозорий.
+lint-not-blp1 = { $version }: Warcraft III очікує BLP1.
+lint-compression = Стиснення { $compression } не підтримується Warcraft III.
+lint-alpha-bits = Глибина альфи { $bits } біт не підтримується.
+lint-alpha-unused = Зберігається { $bits }-бітна альфа, але зображення повністю непрозоре.
+lint-no-mips = Немає мипмапів: текстура мерехтітиме на відстані.
+lint-mips-truncated = Ланцюжок мипів обрізано: { $present } з { $expected } рівнів.
+lint-mip-out-of-bounds = Мип #{ $mip } вказує за кінець файлу.
+lint-jpeg-header-missing = Немає JPEG-заголовка.
+lint-jpeg-header-large = JPEG-заголовок { $len } байт (Warcraft III читає не більше { $max }).
+lint-jpeg-header-truncated = JPEG-заголовок ({ $len } байт) виходить за кінець файлу.
+lint-jpeg-header-overlap = JPEG-заголовок ({ $len } байт) налазить на дані мипів.
+lint-palette-alpha-truncated = Мип #{ $mip }: дані палітри з альфою — { $size } байт, очікувалося { $need }.
+lint-export-no-base = Базовий рівень приховано.
+lint-export-mips-gap = Мип #{ $mip } приховано між видимими рівнями.
+lint-export-mips-truncated = Буде записано лише { $present } з { $expected } рівнів.
+
+quality-preview = Превʼю якості
+quality-preview-hint = Перекодувати зображення у фоні з якістю зі слайдера й показати результат, оцінку розміру .blp і PSNR.
+quality-preview-encoding = Кодування…
+
+mip-gen = Генерувати міпи
+mip-gen-hint = Перебудувати всі рівні з базового обраним фільтром. Згенеровані рівні видно у списку міпів, і вони записуються під час експорту.
+mip-filter-box = Box
+mip-filter-triangle = Трикутний
+mip-filter-lanczos3 = Lanczos3
+mip-filter-kaiser = Кайзер
+mip-gen-gamma = З урахуванням гами
+mip-gen-gamma-hint = Усереднювати в лінійному світлі, щоб контрастні краї не темнішали вдалині
+mip-gen-coverage = Зберігати покриття альфи
+mip-gen-coverage-hint = Зберігати частку пікселів вище порогу альфа-тесту 50%, щоб листя та паркани не рідшали вдалині
+error-quality-preview = Не вдалося побудувати превʼю якості.
+error-loading-screen = Не вдалося нарізати тайли екрана завантаження.
+
+compare = Порівняти…
+compare-hint = Відкрити друге зображення (B) для порівняння з поточним
+compare-side-by-side = Поруч
+compare-swipe = Шторка
+compare-flicker = Мерехтіння
+compare-heatmap = Теплова карта
+compare-swipe-hint = Положення шторки: ліворуч A, праворуч B
+compare-close-hint = Закрити порівняння
+compare-metrics = Різниця A/B
+compare-copy-hint = Скопіювати метрики по мипах текстом
+error-compare-load = Не вдалося відкрити зображення для порівняння: { $path }
+
+batch = Пакетна конвертація
+batch-open = Пакет…
+batch-open-hint = Конвертувати багато файлів або цілі теки в BLP чи PNG
+batch-add-files = Додати файли…
+batch-add-folder = Додати теку…
+batch-clear = Очистити
+batch-mips-limit = Рівні мипів
+batch-same-dir = Поруч із джерелом
+batch-choose-dir = Тека виводу…
+batch-start = Запустити
+batch-cancel = Скасувати
+batch-cancel-hint = Пропустити ще не розпочаті файли; розпочаті буде завершено
+batch-export-report = Експорт звіту…
+batch-queued = У черзі
+batch-decoding = Декодування…
+batch-encoding = Кодування…
+batch-cancelled = Скасовано
+error-batch-same-file = Результат перезаписав би джерело: { $path }
+error-batch-duplicate-output = У цей файл уже пише інший файл пакета: { $path }
+error-batch-worker-lost = Конвертація несподівано перервалася: { $path }
+
 blp-quality = Якість BLP: { $val }
 blp-quality-hint = Якість стискання при експорті BLP. 0 — найгірша, 100 — найкраща.
 
@@ -49,8 +196,44 @@ no-visible-mip-hint = Жоден міп не вибрано.\nСкористай
 
 blp-texture = BLP текстура
 png-image = PNG зображення
+png-mode-base = PNG: базовий рівень
+png-mode-mips = PNG: кожен видимий міп
+png-mode-strip = PNG: міпи однією смугою
+png-mode-hint = Що записувати під час експорту в PNG: лише базовий рівень, кожен видимий міп як name_mipNN.png або всі видимі міпи однією вертикальною смугою.
+
+icon-set = Набір іконок…
+icon-set-hint = Записати іконки командної карти Warcraft III (64×64, з рамкою; DIS* знебарвлені та затемнені) у BLP з поточною якістю:
+
+loading-screen = Екран завантаження
+loading-screen-open = Екран завантаження…
+loading-screen-open-hint = Нарізати зображення на тайли екрана завантаження Warcraft III з превʼю зібраного результату
+loading-screen-layout = Вписано в { $w }×{ $h } без спотворення пропорцій (решта — чорні поля) і нарізано на тайли BLP { $tile }×{ $tile }. Превʼю зібране з декодованих тайлів.
+loading-screen-encoding = Кодування тайлів…
+loading-screen-export = Експорт тайлів…
+loading-screen-export-hint = Записати тайли BLP і маніфест .txt з розкладкою UV та блоком Textures для MDL
+
+pot-npot-source = Розмір джерела — не степінь двійки
+pot-resize = Привести до степеня двійки під час експорту в BLP
+pot-resize-hint = Warcraft III очікує текстури зі сторонами степеня двійки. Ланцюжок міпів будується заново зі зміненого базового рівня.
+pot-resize-edited = Недоступно, поки є вручну імпортовані міпи: новий ланцюжок їх би втратив.
+pot-target-larger = Найближчий більший
+pot-target-smaller = Найближчий менший
+pot-target-explicit = Заданий розмір
+pot-method-lanczos3 = Lanczos3
+pot-method-bilinear = Білінійний
+pot-method-nearest = Найближчий сусід
+pot-method-pad = Доповнити прозорістю
+pot-preview = До / після
+pot-preview-hint = Порівняти джерело із зображенням, яке буде записано
+pot-preview-none = Зображення вже потрібного розміру.
+pot-before = До
+pot-after = Після
 
 error-save-no-image = Немає зображення для збереження
+error-save-blp = Не вдалося закодувати BLP: { $path }
+error-io-write-file = Не вдалося записати файл: { $path } ({ $msg })
+error-save-png = Не вдалося записати PNG: { $path }
+error-save-no-visible-mip = Немає видимих міп-рівнів для збереження
 
 error-poll-decoder = Внутрішня помилка декодера.
 
@@ -72,3 +255,7 @@ error-clipboard-init-failed = Не вдалося отримати доступ
 error-clipboard-no-image = У буфері обміну немає зображення.
 error-clipboard-invalid-buffer = Некоректний буфер зображення (ширина={ $width }, висота={ $height }).
 error-clipboard-encode-png-failed = Не вдалося закодувати PNG із зображення буфера обміну.
+error-clipboard-copy-failed = Не вдалося скопіювати зображення в буфер обміну.
+error-copy-no-image = Цей міп не завантажено.
+error-mip-import-size = Міп { $n } має бути { $expected }, а зображення — { $actual }.
+error-mip-import-index = Міпа { $n } немає: у базового рівня лише { $count } рівнів.
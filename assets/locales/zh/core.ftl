@@ -4,11 +4,31 @@ open-hint = 打开一个文件 ({ $shortcut })
 paste = 粘贴
 paste-hint = 从剪贴板粘贴图像 ({ $shortcut })
 
+copy-image = 复制图像
+copy-image-hint = 将基础层级以 RGBA 复制到剪贴板 ({ $shortcut })
+copy-mip-hint = 将此 Mip 层级复制到剪贴板
+mip-import = 导入层级…
+mip-import-hint = 用尺寸与该层级完全一致的 PNG/BLP 图像替换此 Mip；导出 BLP 时原样写入
+mip-edited = 手动导入
+
+open-folder = 打开文件夹
+open-folder-hint = 浏览文件夹及其子文件夹中的所有图像
+
+prev-file-hint = 此文件夹中的上一张图像（←，PageUp）
+next-file-hint = 此文件夹中的下一张图像（→，PageDown）
+
 pasted-image = 粘贴的图像 (剪贴板)
 drop-hint = 拖动文件到这里或使用 { open } / { paste }
+open-list = 已打开的文件
 
 select-image = 选择图像
 filter-all-images = 所有图像
+select-folder = 选择文件夹
+
+folder-count = 图像：{ $count }
+folder-close-hint = 关闭文件夹视图
+folder-show-gallery-hint = 显示缩略图库
+folder-show-image-hint = 显示已打开的图像
 
 save-as-blp = 保存为 BLP
 save-as-png = 保存为 PNG
@@ -35,20 +55,183 @@ close-error-hint = 关闭错误消息
 copy = 复制
 copy-error-hint = 复制错误消息到剪贴板
 
+cancel = 取消
+cancel-decode-hint = 停止解码此文件 (Esc)
+
+progress-read-start = 正在打开…
+progress-read = 正在读取 { $done } / { $total }
+progress-decode = 正在解码…
+progress-decode-blp = 正在解码图像和 Mipmap…
+progress-mip = 正在生成 Mip { $n } / { $of }
+progress-upload = 正在上传纹理…
+
 no-image = 无图像
 
 mips-all = 全部
 mips-none = 无
 hint-mips-all = 启用所有 mip 级别
 hint-mips-none = 禁用所有 mip 级别
+
+zoom-fit = 适应
+zoom-100 = 100%
+zoom-200 = 200%
+zoom-400 = 400%
+zoom-nearest = 像素
+zoom-nearest-hint = 以清晰方块显示纹素而不平滑。鼠标滚轮以光标为中心缩放，Shift+滚轮滚动，拖动以平移。
+
+channel-rgba-hint = 按原样显示图像（含透明度）
+channel-rgb-hint = 显示颜色，忽略 Alpha
+channel-r-hint = 以灰度显示红色通道
+channel-g-hint = 以灰度显示绿色通道
+channel-b-hint = 以灰度显示蓝色通道
+channel-a-hint = 以灰度显示 Alpha 通道
+
+backdrop-hint = 透明像素后显示的背景
+backdrop-checker = 棋盘格
+backdrop-black = 黑色
+backdrop-white = 白色
+backdrop-magenta = 品红
+backdrop-custom = 自定义颜色
+backdrop-maze = 迷宫
+backdrop-checker-size-hint = 棋盘格单元大小
+
+pinned-pixels = 已固定的像素
+pinned-copy-hint = 将所有已固定的像素复制为文本
+pinned-clear = 清除
+pinned-remove-hint = 点击取消固定
+
+info = 信息
+info-not-blp = 不是 BLP 文件：没有可显示的文件头。
+info-version = 版本
+info-compression = 压缩
+info-alpha-bits = Alpha 位数
+info-alpha-type = Alpha 类型
+info-picture-type = 图片类型
+info-has-mips = 含 Mipmap
+info-size = 尺寸
+info-jpeg-header = JPEG 头
+info-file-size = 文件大小
+info-mips = Mip 表
+yes = 是
+no = 否
+
+lint = WC3 兼容性
+lint-ok = 未发现问题。
+lint-export = 导出为 BLP 时：
+lint-no-image = 没有图像。
+lint-decode-failed = 解码失败：{ $msg }
+lint-npot = 尺寸 { $width }×{ $height } 不是 2 的幂。
+lint-oversized = 尺寸 { $width }×{ $height } 超过 { $max } 像素。
+lint-alpha-all-transparent = 基础层完全透明。
+lint-not-blp1 = { $version }：魔兽争霸 III 需要 BLP1。
+lint-compression = 魔兽争霸 III 不支持 { $compression } 压缩。
+lint-alpha-bits = 不支持 { $bits } 位 Alpha。
+lint-alpha-unused = 存储了 { $bits } 位 Alpha，但图像完全不透明。
+lint-no-mips = 没有 Mipmap：远处纹理会闪烁。
+lint-mips-truncated = Mip 链不完整：{ $present } / { $expected } 级。
+lint-mip-out-of-bounds = Mip #{ $mip } 指向文件末尾之外。
+lint-jpeg-header-missing = 缺少 JPEG 头。
+lint-jpeg-header-large = JPEG 头为 { $len } 字节（魔兽争霸 III 最多读取 { $max }）。
+lint-jpeg-header-truncated = JPEG 头（{ $len } 字节）超出文件末尾。
+lint-jpeg-header-overlap = JPEG 头（{ $len } 字节）与 Mip 数据重叠。
+lint-palette-alpha-truncated = Mip #{ $mip }：带 Alpha 的调色板数据为 { $size } 字节，应为 { $need }。
+lint-export-no-base = 基础层已隐藏。
+lint-export-mips-gap = Mip #{ $mip } 在可见层之间被隐藏。
+lint-export-mips-truncated = 只会写入 { $present } / { $expected } 级 Mip。
+
+quality-preview = 质量预览
+quality-preview-hint = 在后台按滑块质量重新编码图像，显示结果、预计 .blp 大小和 PSNR。
+quality-preview-encoding = 编码中…
+
+mip-gen = 生成 Mip
+mip-gen-hint = 使用所选滤镜从基础层级重建全部 Mip 层级。生成的层级会显示在 Mip 列表中并在导出时写入。
+mip-filter-box = 盒式
+mip-filter-triangle = 三角
+mip-filter-lanczos3 = Lanczos3
+mip-filter-kaiser = Kaiser
+mip-gen-gamma = 伽马校正
+mip-gen-gamma-hint = 在线性光空间中求平均，避免高对比边缘在远处变暗
+mip-gen-coverage = 保持 Alpha 覆盖率
+mip-gen-coverage-hint = 保持高于 50% Alpha 测试阈值的像素比例，使树叶和栅栏在远处不会变稀
+error-quality-preview = 无法生成质量预览。
+error-loading-screen = 无法生成载入画面图块。
+
+compare = 对比…
+compare-hint = 打开第二张图像 (B) 与当前图像对比
+compare-side-by-side = 并排
+compare-swipe = 滑动
+compare-flicker = 闪烁
+compare-heatmap = 热力图
+compare-swipe-hint = 滑块位置：左侧 A，右侧 B
+compare-close-hint = 关闭对比
+compare-metrics = A/B 差异
+compare-copy-hint = 将各级 Mip 指标复制为文本
+error-compare-load = 无法打开用于对比的图像：{ $path }
+
+batch = 批量转换
+batch-open = 批量…
+batch-open-hint = 将多个文件或整个文件夹转换为 BLP 或 PNG
+batch-add-files = 添加文件…
+batch-add-folder = 添加文件夹…
+batch-clear = 清空
+batch-mips-limit = Mip 级数
+batch-same-dir = 与源文件同目录
+batch-choose-dir = 输出文件夹…
+batch-start = 开始
+batch-cancel = 取消
+batch-cancel-hint = 跳过尚未开始的文件；进行中的文件会完成
+batch-export-report = 导出报告…
+batch-queued = 排队中
+batch-decoding = 解码中…
+batch-encoding = 编码中…
+batch-cancelled = 已取消
+error-batch-same-file = 输出会覆盖源文件：{ $path }
+error-batch-duplicate-output = 批量中的另一个文件已写入：{ $path }
+error-batch-worker-lost = 转换意外中止：{ $path }
 blp-quality = BLP 导出质量
 blp-quality-hint = 导出 BLP 时使用的 JPEG 质量
 no-visible-mip-hint = 未选择任何 mip 级别。请使用右侧面板进行选择。
 
 blp-texture = BLP 纹理
 png-image = PNG 图像
+png-mode-base = PNG：基础层级
+png-mode-mips = PNG：每个可见 mip
+png-mode-strip = PNG：mip 拼成一条
+png-mode-hint = PNG 导出内容：仅基础层级、每个可见 mip 单独保存为 name_mipNN.png，或将所有可见 mip 竖向拼接为一张图。
+
+icon-set = 图标套装…
+icon-set-hint = 按当前质量将魔兽争霸 III 命令卡图标（64×64，带边框；DIS* 去色并变暗）写为 BLP：
+
+loading-screen = 载入画面
+loading-screen-open = 载入画面…
+loading-screen-open-hint = 将图像切分为魔兽争霸 III 载入画面图块，并预览拼合结果
+loading-screen-layout = 按原比例缩放至 { $w }×{ $h }（其余部分填充黑边）并切分为 { $tile }×{ $tile } 的 BLP 图块。预览由解码后的图块拼合而成。
+loading-screen-encoding = 正在编码图块…
+loading-screen-export = 导出图块…
+loading-screen-export-hint = 写入 BLP 图块以及包含 UV 布局和 MDL Textures 块的 .txt 清单
+
+pot-npot-source = 源图尺寸不是 2 的幂
+pot-resize = 导出 BLP 时调整为 2 的幂
+pot-resize-hint = 魔兽争霸 III 需要边长为 2 的幂的纹理。Mip 链将从调整后的基础层级重新生成。
+pot-resize-edited = 存在手动导入的 Mip 时不可用：重建的 Mip 链会丢弃它们。
+pot-target-larger = 向上取最近值
+pot-target-smaller = 向下取最近值
+pot-target-explicit = 指定尺寸
+pot-method-lanczos3 = Lanczos3
+pot-method-bilinear = 双线性
+pot-method-nearest = 最近邻
+pot-method-pad = 以透明像素填充
+pot-preview = 前 / 后对比
+pot-preview-hint = 将源图与即将写入的图像进行对比
+pot-preview-none = 图像已是目标尺寸。
+pot-before = 之前
+pot-after = 之后
 
 error-save-no-image = 没有可保存的图像
+error-save-blp = 无法编码 BLP：{ $path }
+error-io-write-file = 无法写入文件：{ $path }（{ $msg }）
+error-save-png = 无法写入 PNG：{ $path }
+error-save-no-visible-mip = 没有可保存的可见 mip 层级
 
 error-poll-decoder = 解码器内部错误。
 
@@ -70,3 +253,7 @@ error-clipboard-init-failed = 无法访问剪贴板。
 error-clipboard-no-image = 剪贴板中未找到图像。
 error-clipboard-invalid-buffer = 剪贴板图像缓冲区无效 (宽度={ $width }, 高度={ $height })。
 error-clipboard-encode-png-failed = 从剪贴板图像编码 PNG 失败。
+error-clipboard-copy-failed = 复制图像到剪贴板失败。
+error-copy-no-image = 此 Mip 层级未加载。
+error-mip-import-size = Mip { $n } 应为 { $expected }，但图像为 { $actual }。
+error-mip-import-index = Mip { $n } 不存在：基础层级只有 { $count } 个层级。
@@ -67,13 +67,13 @@ fn generate_ico(out_dir: &Path, img: &image::RgbaImage, sizes: &[u32]) -> Result
     for &s in sizes {
         let resized = image::imageops::resize(img, s, s, image::imageops::FilterType::Lanczos3);
         let ii = IconImage::from_rgba_data(s, s, resized.into_raw());
-        let entry = ico::IconDirEntry::encode(&ii).map_err(|e| io::Error::new(io::ErrorKind::Other, format!("encode ico {s}px: {e}")))?;
+        let entry = ico::IconDirEntry::encode(&ii).map_err(|e| io::Error::other(format!("encode ico {s}px: {e}")))?;
         dir.add_entry(entry);
     }
     let path = out_dir.join("app.ico");
     let mut f = fs::File::create(&path).map_err(|e| io::Error::new(e.kind(), format!("create {}: {e}", path.display())))?;
     dir.write(&mut f)
-        .map_err(|e| io::Error::new(io::ErrorKind::Other, format!("write {}: {e}", path.display())))?;
+        .map_err(|e| io::Error::other(format!("write {}: {e}", path.display())))?;
     Ok(())
 }
 
@@ -82,17 +82,12 @@ fn embed_windows_resources(out_dir: &Path) -> String {
 
     fn normalize_version(v: &str) -> String {
         let mut parts = [0u16; 4];
-        let mut i = 0usize;
-        for seg in v.split('.') {
-            if i >= 4 {
-                break;
-            }
+        for (part, seg) in parts.iter_mut().zip(v.split('.')) {
             let num = seg
                 .chars()
                 .take_while(|c| c.is_ascii_digit())
                 .collect::<String>();
-            parts[i] = num.parse::<u16>().unwrap_or(0);
-            i += 1;
+            *part = num.parse::<u16>().unwrap_or(0);
         }
         format!("{}.{}.{}.{}", parts[0], parts[1], parts[2], parts[3])
     }
@@ -115,10 +110,10 @@ fn embed_windows_resources(out_dir: &Path) -> String {
     let ico_path = out_dir.join("app.ico");
     let mut res = winresource::WindowsResource::new();
 
-    if let Some(p) = ico_path.to_str() {
-        if StdPath::new(p).exists() {
-            res.set_icon(p);
-        }
+    if let Some(p) = ico_path.to_str()
+        && StdPath::new(p).exists()
+    {
+        res.set_icon(p);
     }
 
     res.set("FileVersion", &ver);
@@ -203,10 +198,10 @@ fn do_macos(src_icon: &Path, out_dir: &Path) -> Result<String, Box<dyn Error>> {
             if let Some(s) = px(kind) {
                 let resized = image::imageops::resize(&img, s, s, image::imageops::FilterType::Lanczos3);
                 let raw = resized.into_raw();
-                let icns_img = IcnsImage::from_data(PixelFormat::RGBA, s, s, raw).map_err(|e| io::Error::new(io::ErrorKind::Other, format!("icns {s}px: {e}")))?;
+                let icns_img = IcnsImage::from_data(PixelFormat::RGBA, s, s, raw).map_err(|e| io::Error::other(format!("icns {s}px: {e}")))?;
                 family
                     .add_icon_with_type(&icns_img, kind)
-                    .map_err(|e| io::Error::new(io::ErrorKind::Other, format!("add slice {s}px: {e}")))?;
+                    .map_err(|e| io::Error::other(format!("add slice {s}px: {e}")))?;
             }
         }
     }
@@ -214,7 +209,7 @@ fn do_macos(src_icon: &Path, out_dir: &Path) -> Result<String, Box<dyn Error>> {
     let mut f = fs::File::create(&icns_path).map_err(|e| io::Error::new(e.kind(), format!("create {}: {e}", icns_path.display())))?;
     family
         .write(&mut f)
-        .map_err(|e| io::Error::new(io::ErrorKind::Other, format!("write {}: {e}", icns_path.display())))?;
+        .map_err(|e| io::Error::other(format!("write {}: {e}", icns_path.display())))?;
 
     Ok(format!(" macOS:\n  - 🧩 Generated {}", icns_path.display()))
 }
@@ -254,7 +249,7 @@ fn do_linux(src_icon: &Path, out_dir: &Path) -> Result<String, Box<dyn Error>> {
         let mut buf = Vec::new();
         image::DynamicImage::ImageRgba8(resized)
             .write_to(&mut io::Cursor::new(&mut buf), image::ImageFormat::Png)
-            .map_err(|e| io::Error::new(io::ErrorKind::Other, format!("encode {}: {e}", path.display())))?;
+            .map_err(|e| io::Error::other(format!("encode {}: {e}", path.display())))?;
 
         let mut f = fs::File::create(&path).map_err(|e| io::Error::new(e.kind(), format!("create {}: {e}", path.display())))?;
         use std::io::Write;
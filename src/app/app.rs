@@ -2,10 +2,27 @@ use crate::error::UiError;
 use crate::app::fonts::install_fonts;
 use crate::ui::i18n::lng_list::LngList;
 use crate::ui::i18n::prefs::load_prefs;
+use crate::export::export_png::PngExportMode;
 use crate::ui::viewer::layout::file_saver::export_quality::export_quality_load;
+use crate::ui::viewer::layout::file_saver::png_export_mode::png_export_mode_load;
+use crate::ui::viewer::layout::folder::folder_view::FolderView;
+use crate::ui::viewer::layout::view::backdrop::backdrop_load;
+use crate::lint::lint_finding::LintFinding;
+use crate::ui::viewer::layout::batch::batch_window::BatchWindow;
+use crate::ui::viewer::layout::compare::compare_state::CompareState;
+use crate::ui::viewer::layout::loading_screen::loading_screen_window::LoadingScreenWindow;
+use crate::ui::viewer::layout::mip_gen::mip_gen_state::MipGenState;
+use crate::ui::viewer::layout::pot_resize::pot_resize_state::PotResizeState;
+use crate::ui::viewer::layout::quality_preview::quality_preview_state::QualityPreview;
+use crate::ui::viewer::layout::view::pixel_sample::PixelSample;
+use crate::ui::viewer::layout::view::view_state::ViewState;
 use crate::ui::viewer::layout::file_saver::save_same_dir::save_same_dir_load;
-use blp::{AnyImage, Blp};
-use blp::BlpError as BlpLibError;
+use crate::decode::blp_info::BlpInfo;
+use crate::decode::cancel_token::CancelToken;
+use crate::decode::decode_progress::{DecodeEvent, DecodeProgress};
+use crate::decode::file_pick_input::Decoded;
+use arboard::Clipboard;
+use blp::{AnyImage, Blp, RgbaImage};
 use eframe::egui::{Context, RawInput, TextureHandle};
 use std::path::PathBuf;
 use std::sync::mpsc::Receiver;
@@ -14,15 +31,38 @@ pub struct App {
     pub lng: LngList,
     pub maximized: bool,
     pub picked_file: Option<PathBuf>,
+    pub open_list: Vec<PathBuf>, // несколько файлов из argv / drag&drop
+    pub folder: Option<FolderView>, // режим просмотра папки
     pub loading: bool,
     pub error: Option<UiError>, // один корень ошибки
     pub image: Option<AnyImage>,
     pub blp: Option<Blp>,
+    pub mip_images: Vec<Option<RgbaImage>>, // len == 16, исходные RGBA уровней
     pub mip_textures: Vec<Option<TextureHandle>>, // len == 16
-    pub decode_rx: Option<Receiver<Result<AnyImage, BlpLibError>>>,
+    pub checker_tex: Option<TextureHandle>, // подложка-шахматка
+    pub blp_info: Option<BlpInfo>, // сырой заголовок BLP (инспектор, палитра)
+    pub lint: Vec<LintFinding>,    // находки линтера WC3 для открытого файла
+    pub decode_rx: Option<Receiver<(u64, DecodeEvent)>>,
+    pub decode_progress: Option<DecodeProgress>, // последний этап для оверлея
+    pub decode_ready: Option<Decoded>,           // декодировано, ждёт заливки в GPU
+    pub decode_gen: u64,             // номер последнего запроса декодирования
+    pub decode_cancel: CancelToken,  // отмена текущего декодирования
     pub mip_visible: [bool; 16], // init: [true; 16]
+    pub mip_edited: [bool; 16],  // уровни, импортированные вручную (поверх декода и генерации)
+    pub mip_edit_gen: u64,       // растёт с каждым импортом уровня — для ключей кэшей поверх decode_gen
+    pub view: ViewState, // масштаб/сдвиг/фильтрация, не сбрасываются при смене файла
+    pub keep_mip_visible: Option<([bool; 16], usize)>, // маска и число уровней прошлого файла — для следующего декода
+    pub pinned: Vec<PixelSample>,             // закреплённые кликом образцы пикселей
+    pub compare: Option<CompareState>,        // вторая картинка для A/B-сравнения
     pub save_same_dir: bool,
     pub export_quality: u8,
+    pub quality_preview: QualityPreview, // живое превью JPEG-качества
+    pub mip_gen: MipGenState,            // своя генерация мипов (фильтр, гамма, покрытие)
+    pub batch: BatchWindow,              // пакетная конвертация
+    pub loading_screen: LoadingScreenWindow, // нарезка экрана загрузки WC3
+    pub pot: PotResizeState,             // приведение к степени двойки при экспорте в BLP
+    pub clipboard: Option<Clipboard>,    // держим, пока жив скопированный кадр (Linux)
+    pub png_export_mode: PngExportMode,
 }
 
 impl App {
@@ -34,19 +74,44 @@ impl App {
         ctx.begin_pass(RawInput::default());
         let _ = ctx.end_pass();
 
+        let export_quality = export_quality_load();
+
         Self {
             lng: load_prefs().lang,
             maximized: false, //
             picked_file: None,
+            open_list: Vec::new(),
+            folder: None,
             decode_rx: None,
+            decode_gen: 0,
+            decode_progress: None,
+            decode_ready: None,
+            decode_cancel: CancelToken::default(),
             loading: false,
             error: None,
             image: None,
             blp: None,
+            blp_info: None,
+            lint: Vec::new(),
+            mip_images: vec![None; 16],
             mip_textures: vec![None; 16],
+            checker_tex: None,
             mip_visible: [true; 16],
+            mip_edited: [false; 16],
+            mip_edit_gen: 0,
+            view: ViewState { backdrop: backdrop_load(), ..Default::default() },
+            keep_mip_visible: None,
+            pinned: Vec::new(),
+            compare: None,
             save_same_dir: save_same_dir_load(),
-            export_quality: export_quality_load(),
+            export_quality,
+            batch: BatchWindow::new(export_quality),
+            loading_screen: LoadingScreenWindow::default(),
+            pot: PotResizeState::default(),
+            quality_preview: QualityPreview::default(),
+            mip_gen: MipGenState::default(),
+            clipboard: None,
+            png_export_mode: png_export_mode_load(),
         }
     }
 }
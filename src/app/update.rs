@@ -15,11 +15,28 @@ impl eframe::App for App {
         }
 
         self.draw_file_picker(ctx);
-        if self.blp.is_some() || self.loading {
+        self.draw_batch_window(ctx);
+        self.draw_loading_screen_window(ctx);
+        self.draw_pot_preview_window(ctx);
+        self.draw_folder_panel(ctx);
+        let gallery = self
+            .folder
+            .as_ref()
+            .is_some_and(|f| f.gallery);
+        if gallery {
+            self.draw_gallery(ctx);
+        } else if self.image.is_some() || self.loading {
             self.draw_panel_left(ctx);
             self.draw_panel_right(ctx);
             self.draw_panel_center(ctx);
         }
         self.poll_decoder(ctx);
+        self.poll_mip_gen(ctx);
+        self.poll_quality_preview(ctx);
+        self.poll_compare(ctx);
+        self.poll_batch(ctx);
+        self.poll_loading_screen(ctx);
+        self.poll_folder_scan(ctx);
+        self.poll_thumbs(ctx);
     }
 }
@@ -0,0 +1,135 @@
+use clap::{Parser, Subcommand, ValueEnum};
+use std::path::PathBuf;
+
+/// Успех.
+pub const EXIT_OK: i32 = 0;
+/// Ошибка выполнения команды (чтение/кодирование/запись).
+pub const EXIT_ERROR: i32 = 1;
+/// Ошибка разбора аргументов (так же отвечает clap).
+#[cfg(not(feature = "ui"))]
+pub const EXIT_USAGE: i32 = 2;
+/// Проба `[PATH]` не прошла: файл не является корректным BLP.
+#[cfg(not(feature = "ui"))]
+pub const EXIT_PROBE_FAILED: i32 = 3;
+/// `lint` нашёл ошибки (с `--strict` — и предупреждения).
+pub const EXIT_LINT_FAILED: i32 = 4;
+
+#[derive(Parser, Debug)]
+#[command(name = "blp", version, about = "Blizzard BLP image format reader/writer (Warcraft/World of Warcraft textures)")]
+#[command(args_conflicts_with_subcommands = true)]
+pub struct Cli {
+    /// CLI-only: check that every file is a valid BLP (exit code 3 if not).
+    /// UI+CLI: open the files in the viewer ("Open With…").
+    pub paths: Vec<PathBuf>,
+
+    #[command(subcommand)]
+    pub command: Option<Command>,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum Command {
+    /// Convert an image into BLP format.
+    ToBlp {
+        /// Input file, usually a PNG.
+        input: PathBuf,
+
+        /// Output path. Defaults to the input with the extension replaced by `.blp`.
+        output: Option<PathBuf>,
+
+        /// Explicit mipmap mask as a sequence of 0/1 values (length 1–16).
+        #[arg(long, num_args = 1..=16, value_parser = parse_mip_bit)]
+        mips: Option<Vec<bool>>,
+
+        /// Limit the number of generated mip levels (1–16). Overrides `--mips`.
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=16))]
+        mips_limit: Option<u8>,
+
+        /// JPEG quality (1–100).
+        #[arg(short, long, default_value_t = 100, value_parser = clap::value_parser!(u8).range(1..=100))]
+        quality: u8,
+    },
+
+    /// Convert a BLP texture into PNG format.
+    ToPng {
+        /// Input file, must be BLP.
+        input: PathBuf,
+
+        /// Output path. Defaults to the input with the extension replaced by `.png`.
+        output: Option<PathBuf>,
+    },
+
+    /// Check textures against Warcraft III expectations (exit code 4 on errors).
+    Lint {
+        /// Files to check (BLP or any supported image).
+        #[arg(required = true)]
+        paths: Vec<PathBuf>,
+
+        /// Output format.
+        #[arg(long, value_enum, default_value_t = LintFormat::Text)]
+        format: LintFormat,
+
+        /// Treat warnings as errors.
+        #[arg(long)]
+        strict: bool,
+    },
+}
+
+/// Формат вывода `lint`: человеку или CI.
+#[derive(ValueEnum, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LintFormat {
+    Text,
+    Json,
+}
+
+fn parse_mip_bit(s: &str) -> Result<bool, String> {
+    match s {
+        "0" => Ok(false),
+        "1" => Ok(true),
+        _ => Err(format!("expected 0 or 1, got {s:?}")),
+    }
+}
+
+/// Маска уровней из `--mips` / `--mips-limit`: по умолчанию всё включено,
+/// `--mips` выключает позиции с нулями, `--mips-limit` обрезает хвост.
+pub fn mip_mask(mips: Option<&[bool]>, mips_limit: Option<u8>) -> [bool; 16] {
+    let mut mask = [true; 16];
+    if let Some(bits) = mips {
+        for (slot, bit) in mask.iter_mut().zip(bits) {
+            *slot = *bit;
+        }
+    }
+    if let Some(limit) = mips_limit {
+        for slot in mask.iter_mut().skip(limit as usize) {
+            *slot = false;
+        }
+    }
+    mask
+}
+
+#[cfg(test)]
+mod tests {
+    use super::mip_mask;
+
+    #[test]
+    fn default_mask_is_all_on() {
+        assert_eq!(mip_mask(None, None), [true; 16]);
+    }
+
+    #[test]
+    fn mips_bits_switch_positions_off() {
+        let mask = mip_mask(Some(&[true, false, true]), None);
+        assert_eq!(&mask[..3], &[true, false, true]);
+        assert!(mask[3..].iter().all(|v| *v));
+    }
+
+    #[test]
+    fn limit_cuts_tail_and_overrides_mips() {
+        let mask = mip_mask(Some(&[true; 16]), Some(4));
+        assert!(mask[..4].iter().all(|v| *v));
+        assert!(mask[4..].iter().all(|v| !*v));
+
+        let mask = mip_mask(Some(&[true, false]), Some(1));
+        assert_eq!(mask.iter().filter(|v| **v).count(), 1);
+        assert!(mask[0]);
+    }
+}
@@ -0,0 +1,51 @@
+use crate::error::UiError;
+use crate::error::ui_error::Cause;
+use std::error::Error;
+use std::fmt::Write as _;
+
+/// Ошибка и вся цепочка `source()` одной строкой: `msg: caused by …: caused by …`.
+pub fn error_chain(e: &(dyn Error + 'static)) -> String {
+    let mut out = e.to_string();
+    let mut cur = e.source();
+    while let Some(cause) = cur {
+        let _ = write!(out, ": caused by {cause}");
+        cur = cause.source();
+    }
+    out
+}
+
+/// То же для `UiError`: `source()` отдаёт только первую причину, здесь — все из `causes`.
+pub fn ui_error_chain(e: &UiError) -> String {
+    let mut out = e.to_string();
+    for cause in &e.causes {
+        let cause: &(dyn Error + 'static) = match cause {
+            Cause::Blp(e) => e,
+            Cause::Std(e) => e.as_ref(),
+        };
+        let _ = write!(out, ": caused by {}", error_chain(cause));
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io;
+
+    #[test]
+    fn prints_every_cause() {
+        let e = UiError::new("error-io-write-file")
+            .with_arg("path", "a.blp")
+            .push_std(io::Error::other("disk full"))
+            .push_std(io::Error::other("retry failed"));
+        assert_eq!(ui_error_chain(&e), r#"error-io-write-file(path="a.blp"): caused by disk full: caused by retry failed"#);
+        assert_eq!(ui_error_chain(&UiError::new("x")), "x()");
+    }
+
+    #[test]
+    fn walks_nested_sources() {
+        let inner = UiError::new("inner").push_std(io::Error::other("root cause"));
+        let e = UiError::new("outer").push_std(inner);
+        assert_eq!(ui_error_chain(&e), "outer(): caused by inner(): caused by root cause");
+    }
+}
@@ -0,0 +1,155 @@
+use crate::cli::args::{EXIT_LINT_FAILED, EXIT_OK, LintFormat};
+use crate::cli::error_chain::error_chain;
+use crate::decode::cancel_token::CancelToken;
+use crate::decode::file_pick_input::FilePickInput;
+use crate::lint::lint_finding::{LintFinding, LintSeverity};
+use crate::lint::lint_texture::lint_texture;
+use std::path::{Path, PathBuf};
+
+/// Проверяет файлы и печатает находки в stdout; код выхода — для CI.
+pub fn lint(paths: &[PathBuf], format: LintFormat, strict: bool) -> i32 {
+    let reports: Vec<(&Path, Vec<LintFinding>)> = paths
+        .iter()
+        .map(|p| (p.as_path(), lint_file(p)))
+        .collect();
+
+    match format {
+        LintFormat::Text => {
+            for (path, findings) in &reports {
+                for f in findings {
+                    let args: Vec<String> = f
+                        .args
+                        .iter()
+                        .map(|(k, v)| format!("{k}={v}"))
+                        .collect();
+                    let line = format!("{}: {} {} {}", path.display(), f.severity.as_str(), f.code, args.join(" "));
+                    println!("{}", line.trim_end());
+                }
+            }
+        }
+        LintFormat::Json => println!("{}", reports_json(&reports)),
+    }
+
+    exit_code(&reports, strict)
+}
+
+/// Ошибки валят проверку всегда, предупреждения — только со `--strict`.
+fn exit_code(reports: &[(&Path, Vec<LintFinding>)], strict: bool) -> i32 {
+    let min = if strict { LintSeverity::Warning } else { LintSeverity::Error };
+    let failed = reports
+        .iter()
+        .flat_map(|(_, f)| f)
+        .any(|f| f.severity >= min);
+    if failed { EXIT_LINT_FAILED } else { EXIT_OK }
+}
+
+fn lint_file(path: &Path) -> Vec<LintFinding> {
+    match FilePickInput::Path(path.to_path_buf()).decode_with_info(&CancelToken::default(), |_| {}) {
+        Ok(d) => lint_texture(d.blp_info.as_ref(), &d.mips),
+        Err(e) => vec![LintFinding::new(LintSeverity::Error, "decode-failed").with_arg("msg", error_chain(&e))],
+    }
+}
+
+/// `[{"path": "...", "findings": [{"severity": "...", "code": "...", "args": {...}}]}]`
+fn reports_json(reports: &[(&Path, Vec<LintFinding>)]) -> String {
+    let files: Vec<String> = reports
+        .iter()
+        .map(|(path, findings)| {
+            let items: Vec<String> = findings
+                .iter()
+                .map(|f| {
+                    let args: Vec<String> = f
+                        .args
+                        .iter()
+                        .map(|(k, v)| format!("{}:{}", json_str(k), json_str(v)))
+                        .collect();
+                    format!(r#"{{"severity":{},"code":{},"args":{{{}}}}}"#, json_str(f.severity.as_str()), json_str(f.code), args.join(","))
+                })
+                .collect();
+            format!(r#"{{"path":{},"findings":[{}]}}"#, json_str(&path.to_string_lossy()), items.join(","))
+        })
+        .collect();
+    format!("[{}]", files.join(","))
+}
+
+fn json_str(s: &str) -> String {
+    let mut out = String::with_capacity(s.len() + 2);
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use blp::RgbaImage;
+
+    #[test]
+    fn json_str_escapes() {
+        assert_eq!(json_str("plain"), r#""plain""#);
+        assert_eq!(json_str(r#"a"b\c"#), r#""a\"b\\c""#);
+        assert_eq!(json_str("l1\nl2\r\t"), r#""l1\nl2\r\t""#);
+        assert_eq!(json_str("\u{1}\u{1f}"), r#""\u0001\u001f""#);
+        assert_eq!(json_str("текстура"), r#""текстура""#);
+    }
+
+    #[test]
+    fn reports_json_shape() {
+        let findings = vec![
+            LintFinding::new(LintSeverity::Error, "npot")
+                .with_arg("width", 100)
+                .with_arg("height", 60),
+        ];
+        let reports = [(Path::new(r"dir\a.blp"), findings), (Path::new("b.blp"), vec![])];
+        assert_eq!(
+            reports_json(&reports),
+            r#"[{"path":"dir\\a.blp","findings":[{"severity":"error","code":"npot","args":{"width":"100","height":"60"}}]},{"path":"b.blp","findings":[]}]"#
+        );
+        assert_eq!(reports_json(&[]), "[]");
+    }
+
+    #[test]
+    fn exit_codes() {
+        let warning = vec![LintFinding::new(LintSeverity::Warning, "oversized")];
+        let error = vec![LintFinding::new(LintSeverity::Error, "npot")];
+        let path = Path::new("a.blp");
+
+        for strict in [false, true] {
+            assert_eq!(exit_code(&[], strict), EXIT_OK);
+            assert_eq!(exit_code(&[(path, vec![])], strict), EXIT_OK);
+            assert_eq!(exit_code(&[(path, vec![]), (path, error.clone())], strict), EXIT_LINT_FAILED);
+        }
+        assert_eq!(exit_code(&[(path, warning.clone())], false), EXIT_OK);
+        assert_eq!(exit_code(&[(path, warning)], true), EXIT_LINT_FAILED);
+    }
+
+    #[test]
+    fn lint_files_end_to_end() {
+        let dir = std::env::temp_dir().join(format!("blp-lint-test-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let clean = dir.join("clean.png");
+        let wide = dir.join("wide.png");
+        let broken = dir.join("broken.png");
+        RgbaImage::from_pixel(8, 8, blp::image::Rgba([1, 2, 3, 255])).save(&clean).unwrap();
+        RgbaImage::from_pixel(2048, 1, blp::image::Rgba([1, 2, 3, 255])).save(&wide).unwrap();
+        std::fs::write(&broken, b"not an image").unwrap();
+
+        assert_eq!(lint(std::slice::from_ref(&clean), LintFormat::Json, true), EXIT_OK);
+        assert_eq!(lint(std::slice::from_ref(&wide), LintFormat::Json, false), EXIT_OK);
+        assert_eq!(lint(std::slice::from_ref(&wide), LintFormat::Text, true), EXIT_LINT_FAILED);
+        assert_eq!(lint(&[clean, broken], LintFormat::Text, false), EXIT_LINT_FAILED);
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}
@@ -0,0 +1,8 @@
+pub mod args;
+pub mod error_chain;
+pub mod lint;
+#[cfg(not(feature = "ui"))]
+pub mod probe;
+pub mod run_command;
+pub mod to_blp;
+pub mod to_png;
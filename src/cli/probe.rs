@@ -0,0 +1,20 @@
+use crate::cli::args::{EXIT_OK, EXIT_PROBE_FAILED};
+use crate::cli::error_chain::error_chain;
+use crate::decode::file_pick_input::FilePickInput;
+use blp::AnyImageData;
+use std::path::Path;
+
+/// Санити-проба: файл читается и декодируется как BLP.
+pub fn probe(path: &Path) -> i32 {
+    match FilePickInput::Path(path.to_path_buf()).decode() {
+        Ok(img) if matches!(img.data, AnyImageData::Blp(_)) => EXIT_OK,
+        Ok(_) => {
+            eprintln!("blp: not a BLP file: {}", path.display());
+            EXIT_PROBE_FAILED
+        }
+        Err(e) => {
+            eprintln!("blp: {}: {}", path.display(), error_chain(&e));
+            EXIT_PROBE_FAILED
+        }
+    }
+}
@@ -0,0 +1,21 @@
+use crate::cli::args::{Command, EXIT_OK, mip_mask};
+use crate::cli::lint::lint;
+use crate::cli::to_blp::to_blp;
+use crate::cli::to_png::to_png;
+use crate::error::UiError;
+
+/// Выполняет команду и возвращает код выхода процесса.
+pub fn run_command(cmd: Command) -> Result<i32, UiError> {
+    match cmd {
+        Command::ToBlp { input, output, mips, mips_limit, quality } => {
+            let output = output.unwrap_or_else(|| input.with_extension("blp"));
+            let mask = mip_mask(mips.as_deref(), mips_limit);
+            to_blp(&input, &output, quality, &mask).map(|_| EXIT_OK)
+        }
+        Command::ToPng { input, output } => {
+            let output = output.unwrap_or_else(|| input.with_extension("png"));
+            to_png(&input, &output).map(|_| EXIT_OK)
+        }
+        Command::Lint { paths, format, strict } => Ok(lint(&paths, format, strict)),
+    }
+}
@@ -0,0 +1,17 @@
+use crate::decode::any_image_mips::any_image_mips;
+use crate::decode::file_pick_input::FilePickInput;
+use crate::error::UiError;
+use crate::export::export_blp::export_blp;
+use std::path::Path;
+
+pub fn to_blp(input: &Path, output: &Path, quality: u8, mip_visible: &[bool; 16]) -> Result<(), UiError> {
+    let img = FilePickInput::Path(input.to_path_buf())
+        .decode()
+        .map_err(|e| {
+            UiError::new("error-image-load")
+                .with_arg("path", input.to_string_lossy().into_owned())
+                .push_blp(e)
+        })?;
+    let mips = any_image_mips(&img).map_err(|e| UiError::new("error-image-load").push_blp(e))?;
+    export_blp(output, &mips, quality, mip_visible)
+}
@@ -0,0 +1,21 @@
+use crate::decode::any_image_mips::any_image_mips;
+use crate::decode::file_pick_input::FilePickInput;
+use crate::error::UiError;
+use crate::export::export_png::{PngExportMode, export_png};
+use blp::AnyImageData;
+use std::path::Path;
+
+pub fn to_png(input: &Path, output: &Path) -> Result<(), UiError> {
+    let img = FilePickInput::Path(input.to_path_buf())
+        .decode()
+        .map_err(|e| {
+            UiError::new("error-image-load")
+                .with_arg("path", input.to_string_lossy().into_owned())
+                .push_blp(e)
+        })?;
+    if !matches!(img.data, AnyImageData::Blp(_)) {
+        return Err(UiError::new("error-not-blp").with_arg("path", input.to_string_lossy().into_owned()));
+    }
+    let mips = any_image_mips(&img).map_err(|e| UiError::new("error-image-load").push_blp(e))?;
+    export_png(output, &mips, PngExportMode::Base, &[true; 16])
+}
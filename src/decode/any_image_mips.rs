@@ -0,0 +1,24 @@
+use crate::export::mip_chain::build_mip_chain_with;
+use blp::{AnyImage, AnyImageData, BlpError, RgbaImage};
+
+/// Разворачивает декодированное изображение в плоский список уровней.
+/// - BLP: все мипы как есть (пропуски — `None`);
+/// - PNG/JPG/PSD/буфер: базовый уровень + досчитанная цепочка (то, что уйдёт в BLP).
+pub fn any_image_mips(img: &AnyImage) -> Result<Vec<Option<RgbaImage>>, BlpError> {
+    any_image_mips_with(img, |_, _| {})
+}
+
+/// То же, с отчётом `on_mip(n, of)` по мере досчёта уровней.
+/// У BLP уровни уже декодированы в `AnyImage::from_buffer` — отчитываться не о чем.
+pub fn any_image_mips_with(img: &AnyImage, on_mip: impl FnMut(usize, usize)) -> Result<Vec<Option<RgbaImage>>, BlpError> {
+    let mut mips: Vec<Option<RgbaImage>> = match &img.data {
+        AnyImageData::Blp(blp) => blp.mipmaps.iter().map(|m| m.image.clone()).collect(),
+        _ => build_mip_chain_with(&[Some(img.to_rgba()?)], on_mip)
+            .unwrap_or_default()
+            .into_iter()
+            .map(Some)
+            .collect(),
+    };
+    mips.resize(16, None);
+    Ok(mips)
+}
@@ -0,0 +1,277 @@
+/// Версия контейнера по магии.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BlpVersion {
+    Blp0,
+    Blp1,
+    Blp2,
+}
+
+/// Способ хранения пикселей.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BlpCompression {
+    Jpeg,
+    Palette,
+    Dxt,
+    Argb8888,
+    Unknown(u32),
+}
+
+/// Запись таблицы мипов из заголовка.
+#[derive(Clone, Copy, Debug)]
+pub struct BlpMipInfo {
+    pub offset: u32,
+    pub size: u32,
+    pub width: u32,
+    pub height: u32,
+}
+
+/// Всё, что известно о BLP по сырому заголовку (без декодирования пикселей).
+#[derive(Clone, Debug)]
+pub struct BlpInfo {
+    pub version: BlpVersion,
+    pub compression: BlpCompression,
+    pub alpha_bits: u32,
+    /// Только BLP2.
+    #[cfg_attr(not(feature = "ui"), allow(dead_code))]
+    pub alpha_type: Option<u8>,
+    /// Только BLP0/BLP1 (поле `extra`/picture type).
+    #[cfg_attr(not(feature = "ui"), allow(dead_code))]
+    pub picture_type: Option<u32>,
+    pub has_mipmaps: bool,
+    pub width: u32,
+    pub height: u32,
+    /// Ровно 16 записей, как в заголовке (пустые — offset/size = 0).
+    pub mips: Vec<BlpMipInfo>,
+    /// Длина общего JPEG-заголовка (только JPEG).
+    pub jpeg_header_len: Option<u32>,
+    pub file_len: usize,
+    /// Плоскости индексов палитры по мипам (только палитровые BLP1/BLP2).
+    #[cfg_attr(not(feature = "ui"), allow(dead_code))]
+    pub palette_indices: Vec<Option<Vec<u8>>>,
+}
+
+impl BlpVersion {
+    pub fn label(self) -> &'static str {
+        match self {
+            BlpVersion::Blp0 => "BLP0",
+            BlpVersion::Blp1 => "BLP1",
+            BlpVersion::Blp2 => "BLP2",
+        }
+    }
+}
+
+impl BlpCompression {
+    pub fn label(self) -> String {
+        match self {
+            BlpCompression::Jpeg => "JPEG".into(),
+            BlpCompression::Palette => "Palette".into(),
+            BlpCompression::Dxt => "DXT".into(),
+            BlpCompression::Argb8888 => "ARGB8888".into(),
+            BlpCompression::Unknown(n) => format!("Unknown ({n})"),
+        }
+    }
+}
+
+#[inline]
+fn u32_le(b: &[u8], at: usize) -> Option<u32> {
+    Some(u32::from_le_bytes(b.get(at..at + 4)?.try_into().ok()?))
+}
+
+const MIP_TABLE_BLP1: usize = 28;
+const MIP_TABLE_BLP2: usize = 20;
+/// Размер фиксированного заголовка (после него — общий JPEG-заголовок или палитра).
+pub const HEADER_BLP1: usize = MIP_TABLE_BLP1 + 128;
+pub const HEADER_BLP2: usize = MIP_TABLE_BLP2 + 128;
+
+impl BlpInfo {
+    /// Разбирает заголовок BLP0/BLP1/BLP2. `None` — это не BLP или файл обрезан до таблицы мипов.
+    pub fn parse(bytes: &[u8]) -> Option<BlpInfo> {
+        let version = match bytes.get(0..4)? {
+            b"BLP0" => BlpVersion::Blp0,
+            b"BLP1" => BlpVersion::Blp1,
+            b"BLP2" => BlpVersion::Blp2,
+            _ => return None,
+        };
+
+        let (compression, alpha_bits, alpha_type, picture_type, has_mipmaps, table, header_len) = match version {
+            BlpVersion::Blp0 | BlpVersion::Blp1 => {
+                let compression = match u32_le(bytes, 4)? {
+                    0 => BlpCompression::Jpeg,
+                    1 => BlpCompression::Palette,
+                    n => BlpCompression::Unknown(n),
+                };
+                (compression, u32_le(bytes, 8)?, None, Some(u32_le(bytes, 20)?), u32_le(bytes, 24)? != 0, MIP_TABLE_BLP1, HEADER_BLP1)
+            }
+            BlpVersion::Blp2 => {
+                let kind = u32_le(bytes, 4)?;
+                let comp = *bytes.get(8)?;
+                let compression = match (kind, comp) {
+                    (0, _) => BlpCompression::Jpeg,
+                    (1, 1) => BlpCompression::Palette,
+                    (1, 2) => BlpCompression::Dxt,
+                    (1, 3) => BlpCompression::Argb8888,
+                    (_, c) => BlpCompression::Unknown(c as u32),
+                };
+                (compression, *bytes.get(9)? as u32, Some(*bytes.get(10)?), None, *bytes.get(11)? != 0, MIP_TABLE_BLP2, HEADER_BLP2)
+            }
+        };
+
+        let width = u32_le(bytes, 12)?;
+        let height = u32_le(bytes, 16)?;
+
+        let mut mips = Vec::with_capacity(16);
+        for i in 0..16 {
+            mips.push(BlpMipInfo {
+                offset: u32_le(bytes, table + i * 4)?,
+                size: u32_le(bytes, table + 64 + i * 4)?,
+                width: (width >> i).max(1),
+                height: (height >> i).max(1),
+            });
+        }
+
+        let jpeg_header_len = match compression {
+            BlpCompression::Jpeg => u32_le(bytes, header_len),
+            _ => None,
+        };
+
+        let palette_indices = match compression {
+            BlpCompression::Palette => mips
+                .iter()
+                .map(|m| {
+                    let n = m.width as usize * m.height as usize;
+                    let start = m.offset as usize;
+                    (m.offset != 0 && m.size != 0)
+                        .then(|| bytes.get(start..start + n).map(<[u8]>::to_vec))
+                        .flatten()
+                })
+                .collect(),
+            _ => vec![None; 16],
+        };
+
+        Some(BlpInfo { version, compression, alpha_bits, alpha_type, picture_type, has_mipmaps, width, height, mips, jpeg_header_len, file_len: bytes.len(), palette_indices })
+    }
+
+    /// Индекс палитры текселя (палитровые BLP).
+    #[cfg(feature = "ui")]
+    pub fn palette_index(&self, mip: usize, x: u32, y: u32) -> Option<u8> {
+        let m = self.mips.get(mip)?;
+        let plane = self.palette_indices.get(mip)?.as_ref()?;
+        plane
+            .get(y as usize * m.width as usize + x as usize)
+            .copied()
+    }
+
+    /// Номер JPEG-блока 8×8 (построчно) для JPEG BLP.
+    #[cfg(feature = "ui")]
+    pub fn jpeg_block(&self, mip: usize, x: u32, y: u32) -> Option<u32> {
+        if self.compression != BlpCompression::Jpeg {
+            return None;
+        }
+        let m = self.mips.get(mip)?;
+        Some((y / 8) * m.width.div_ceil(8) + x / 8)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn put(b: &mut [u8], at: usize, v: u32) {
+        b[at..at + 4].copy_from_slice(&v.to_le_bytes());
+    }
+
+    /// BLP1 JPEG 16×8: два мипа, общий JPEG-заголовок длиной 10.
+    fn blp1_jpeg() -> Vec<u8> {
+        let mut b = vec![0u8; HEADER_BLP1 + 4 + 10];
+        b[..4].copy_from_slice(b"BLP1");
+        put(&mut b, 4, 0); // JPEG
+        put(&mut b, 8, 8); // alpha bits
+        put(&mut b, 12, 16);
+        put(&mut b, 16, 8);
+        put(&mut b, 20, 5); // picture type
+        put(&mut b, 24, 1); // has mipmaps
+        put(&mut b, MIP_TABLE_BLP1, 170);
+        put(&mut b, MIP_TABLE_BLP1 + 4, 180);
+        put(&mut b, MIP_TABLE_BLP1 + 64, 10);
+        put(&mut b, MIP_TABLE_BLP1 + 68, 4);
+        put(&mut b, HEADER_BLP1, 10);
+        b
+    }
+
+    #[test]
+    fn blp1_jpeg_header() {
+        let info = BlpInfo::parse(&blp1_jpeg()).unwrap();
+        assert_eq!(info.version, BlpVersion::Blp1);
+        assert_eq!(info.compression, BlpCompression::Jpeg);
+        assert_eq!((info.width, info.height), (16, 8));
+        assert_eq!(info.alpha_bits, 8);
+        assert_eq!(info.picture_type, Some(5));
+        assert_eq!(info.alpha_type, None);
+        assert!(info.has_mipmaps);
+        assert_eq!(info.jpeg_header_len, Some(10));
+        assert_eq!(info.mips.len(), 16);
+        assert_eq!((info.mips[0].offset, info.mips[0].size), (170, 10));
+        assert_eq!((info.mips[1].offset, info.mips[1].size), (180, 4));
+        assert_eq!((info.mips[1].width, info.mips[1].height), (8, 4));
+        // хвост цепочки не меньше 1×1
+        assert_eq!((info.mips[15].width, info.mips[15].height), (1, 1));
+    }
+
+    #[cfg(feature = "ui")]
+    #[test]
+    fn blp2_palette_indices() {
+        // 2×2 палитровый BLP2, индексы сразу после палитры
+        let at = HEADER_BLP2 + 1024;
+        let mut b = vec![0u8; at + 4];
+        b[..4].copy_from_slice(b"BLP2");
+        put(&mut b, 4, 1);
+        b[8] = 1; // palette
+        b[9] = 8;
+        b[10] = 7;
+        put(&mut b, 12, 2);
+        put(&mut b, 16, 2);
+        put(&mut b, MIP_TABLE_BLP2, at as u32);
+        put(&mut b, MIP_TABLE_BLP2 + 64, 4);
+        b[at..at + 4].copy_from_slice(&[3, 1, 4, 1]);
+
+        let info = BlpInfo::parse(&b).unwrap();
+        assert_eq!(info.version, BlpVersion::Blp2);
+        assert_eq!(info.compression, BlpCompression::Palette);
+        assert_eq!(info.alpha_type, Some(7));
+        assert_eq!(info.picture_type, None);
+        assert!(!info.has_mipmaps);
+        assert_eq!(info.jpeg_header_len, None);
+        assert_eq!(info.palette_index(0, 0, 0), Some(3));
+        assert_eq!(info.palette_index(0, 0, 1), Some(4));
+        assert_eq!(info.palette_index(1, 0, 0), None);
+        assert_eq!(info.jpeg_block(0, 0, 0), None);
+    }
+
+    #[test]
+    fn blp2_compression_kinds() {
+        let mut b = vec![0u8; HEADER_BLP2];
+        b[..4].copy_from_slice(b"BLP2");
+        put(&mut b, 4, 1);
+        for (comp, expected) in [(2, BlpCompression::Dxt), (3, BlpCompression::Argb8888), (9, BlpCompression::Unknown(9))] {
+            b[8] = comp;
+            assert_eq!(BlpInfo::parse(&b).unwrap().compression, expected);
+        }
+    }
+
+    #[cfg(feature = "ui")]
+    #[test]
+    fn jpeg_block_row_major() {
+        let info = BlpInfo::parse(&blp1_jpeg()).unwrap();
+        // 16 px в ширину — два блока в строке
+        assert_eq!(info.jpeg_block(0, 0, 0), Some(0));
+        assert_eq!(info.jpeg_block(0, 9, 0), Some(1));
+        assert_eq!(info.jpeg_block(0, 9, 7), Some(1));
+        assert_eq!(info.jpeg_block(1, 0, 0), Some(0));
+    }
+
+    #[test]
+    fn not_blp_or_truncated() {
+        assert!(BlpInfo::parse(b"\x89PNG\r\n\x1a\n").is_none());
+        assert!(BlpInfo::parse(&blp1_jpeg()[..60]).is_none());
+    }
+}
@@ -0,0 +1,28 @@
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// Токен отмены фонового декодирования: UI взводит, воркер проверяет между этапами.
+#[derive(Clone, Default, Debug)]
+pub struct CancelToken(Arc<AtomicBool>);
+
+impl CancelToken {
+    #[cfg(feature = "ui")]
+    #[inline]
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::SeqCst);
+    }
+
+    #[inline]
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::SeqCst)
+    }
+
+    /// `Err(decode-cancelled)`, если отменили, — удобно для `?` в воркере.
+    #[inline]
+    pub fn check(&self) -> Result<(), blp::BlpError> {
+        if self.is_cancelled() { Err(blp::BlpError::new(DECODE_CANCELLED)) } else { Ok(()) }
+    }
+}
+
+/// Ключ ошибки отмены: не показывается пользователю.
+pub const DECODE_CANCELLED: &str = "decode-cancelled";
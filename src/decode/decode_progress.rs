@@ -0,0 +1,24 @@
+#[cfg(feature = "ui")]
+use crate::decode::file_pick_input::Decoded;
+
+/// Этап фонового декодирования (для прогресс-бара).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DecodeProgress {
+    /// Прочитано `done` из `total` байт (`total == 0` — размер неизвестен).
+    Read { done: u64, total: u64 },
+    /// Разбор контейнера/пикселей внутри декодера (без промежуточных отметок).
+    /// `blp` — у BLP здесь же декодируются все уровни, отдельного этапа мипов нет.
+    Decode { blp: bool },
+    /// Готов уровень `n` из `of` (1-based).
+    Mip { n: usize, of: usize },
+    /// Заливка текстур в GPU (уже в UI-потоке).
+    #[cfg(feature = "ui")]
+    Upload,
+}
+
+/// Сообщение воркера декодирования.
+#[cfg(feature = "ui")]
+pub enum DecodeEvent {
+    Progress(DecodeProgress),
+    Done(Result<Decoded, blp::BlpError>),
+}
@@ -0,0 +1,86 @@
+use blp::{AnyImage, RgbaImage};
+use crate::decode::any_image_mips::any_image_mips_with;
+use crate::decode::blp_info::BlpInfo;
+use crate::decode::cancel_token::CancelToken;
+use crate::decode::decode_progress::DecodeProgress;
+use crate::ext::path::ensure_readable::EnsureReadable;
+use std::fs::File;
+use std::io::Read;
+use std::path::PathBuf;
+
+/// Размер порции чтения: между порциями проверяется отмена.
+const READ_CHUNK: usize = 1 << 20;
+
+/// Результат для вьювера: картинка, разбор сырого заголовка BLP и уровни.
+pub struct Decoded {
+    #[cfg_attr(not(feature = "ui"), allow(dead_code))]
+    pub image: AnyImage,
+    pub blp_info: Option<BlpInfo>,
+    pub mips: Vec<Option<RgbaImage>>,
+}
+
+pub enum FilePickInput {
+    Path(PathBuf),
+    /// Буфер обмена (только UI).
+    #[cfg_attr(not(feature = "ui"), allow(dead_code))]
+    Bytes(Vec<u8>),
+}
+
+impl FilePickInput {
+    fn into_bytes(self) -> Result<Vec<u8>, blp::BlpError> {
+        match self {
+            FilePickInput::Path(path) => {
+                path.as_path().read_all()
+                    .map_err(|_e| blp::BlpError::new("ui-read-error"))
+            }
+            FilePickInput::Bytes(data) => Ok(data),
+        }
+    }
+
+    /// Как `into_bytes`, но файл читается порциями с проверкой отмены.
+    fn into_bytes_cancellable(self, cancel: &CancelToken, progress: &impl Fn(DecodeProgress)) -> Result<Vec<u8>, blp::BlpError> {
+        let path = match self {
+            FilePickInput::Path(path) => path,
+            FilePickInput::Bytes(data) => return Ok(data),
+        };
+        let read_err = |_e: std::io::Error| blp::BlpError::new("ui-read-error");
+        let mut file = File::open(&path).map_err(read_err)?;
+        let total = file
+            .metadata()
+            .map(|m| m.len())
+            .unwrap_or(0);
+
+        let mut data = Vec::with_capacity(total as usize);
+        let mut chunk = vec![0u8; READ_CHUNK];
+        loop {
+            cancel.check()?;
+            let n = file.read(&mut chunk).map_err(read_err)?;
+            if n == 0 {
+                break;
+            }
+            data.extend_from_slice(&chunk[..n]);
+            progress(DecodeProgress::Read { done: data.len() as u64, total });
+        }
+        Ok(data)
+    }
+
+    pub fn decode(self) -> Result<AnyImage, blp::BlpError> {
+        let data = self.into_bytes()?;
+        let img = AnyImage::from_buffer(&data)?;
+        Ok(img)
+    }
+
+    /// Полное декодирование для вьювера; между этапами проверяется `cancel`,
+    /// о переходах между этапами сообщается через `progress`.
+    pub fn decode_with_info(self, cancel: &CancelToken, progress: impl Fn(DecodeProgress)) -> Result<Decoded, blp::BlpError> {
+        let data = self.into_bytes_cancellable(cancel, &progress)?;
+        let blp_info = BlpInfo::parse(&data);
+        cancel.check()?;
+        progress(DecodeProgress::Decode { blp: blp_info.is_some() });
+        let image = AnyImage::from_buffer(&data)?;
+        cancel.check()?;
+        let mips = any_image_mips_with(&image, |n, of| progress(DecodeProgress::Mip { n, of }))?;
+        cancel.check()?;
+        Ok(Decoded { image, blp_info, mips })
+    }
+}
@@ -0,0 +1,76 @@
+use std::fs::File;
+use std::io::Read;
+use std::path::Path;
+
+#[inline]
+fn u32_le(b: &[u8], at: usize) -> Option<u32> {
+    Some(u32::from_le_bytes(b.get(at..at + 4)?.try_into().ok()?))
+}
+
+#[inline]
+fn u32_be(b: &[u8], at: usize) -> Option<u32> {
+    Some(u32::from_be_bytes(b.get(at..at + 4)?.try_into().ok()?))
+}
+
+/// Размеры изображения по заголовку, без полного декодирования.
+/// - BLP0/BLP1/BLP2: width/height лежат по смещениям 12 и 16 (LE);
+/// - PSD: height/width по смещениям 14 и 18 (BE);
+/// - остальное — через `image::image_dimensions`.
+pub fn image_dims(path: &Path) -> Option<(u32, u32)> {
+    let mut head = Vec::with_capacity(32);
+    File::open(path)
+        .ok()?
+        .take(32)
+        .read_to_end(&mut head)
+        .ok()?;
+
+    match head.get(0..4)? {
+        b"BLP0" | b"BLP1" | b"BLP2" => Some((u32_le(&head, 12)?, u32_le(&head, 16)?)),
+        b"8BPS" => Some((u32_be(&head, 18)?, u32_be(&head, 14)?)),
+        _ => blp::image::image_dimensions(path).ok(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::image_dims;
+    use std::fs;
+    use std::path::PathBuf;
+
+    fn temp_file(name: &str, bytes: &[u8]) -> PathBuf {
+        let path = std::env::temp_dir().join(format!("blp-ui-image-dims-{}-{name}", std::process::id()));
+        fs::write(&path, bytes).unwrap();
+        path
+    }
+
+    #[test]
+    fn blp_header_le_at_12_and_16() {
+        for magic in [b"BLP0", b"BLP1", b"BLP2"] {
+            let mut head = vec![0u8; 32];
+            head[..4].copy_from_slice(magic);
+            head[12..16].copy_from_slice(&256u32.to_le_bytes());
+            head[16..20].copy_from_slice(&128u32.to_le_bytes());
+            let path = temp_file("tex.blp", &head);
+            assert_eq!(image_dims(&path), Some((256, 128)));
+            fs::remove_file(path).unwrap();
+        }
+    }
+
+    #[test]
+    fn psd_header_be_height_then_width() {
+        let mut head = vec![0u8; 26];
+        head[..4].copy_from_slice(b"8BPS");
+        head[14..18].copy_from_slice(&300u32.to_be_bytes());
+        head[18..22].copy_from_slice(&500u32.to_be_bytes());
+        let path = temp_file("img.psd", &head);
+        assert_eq!(image_dims(&path), Some((500, 300)));
+        fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn truncated_blp_header() {
+        let path = temp_file("short.blp", b"BLP1\0\0\0\0\0\0\0\0\x40\0\0\0");
+        assert_eq!(image_dims(&path), None);
+        fs::remove_file(path).unwrap();
+    }
+}
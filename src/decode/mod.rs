@@ -0,0 +1,7 @@
+pub mod any_image_mips;
+pub mod blp_info;
+pub mod cancel_token;
+pub mod decode_progress;
+pub mod file_pick_input;
+#[cfg(feature = "ui")]
+pub mod image_dims;
@@ -30,6 +30,7 @@ impl UiError {
         self
     }
 
+    #[cfg(feature = "ui")]
     #[inline]
     pub fn with_args(mut self, args: impl IntoIterator<Item = (&'static str, String)>) -> Self {
         for (k, v) in args {
@@ -51,6 +52,7 @@ impl UiError {
         self
     }
 
+    #[cfg(feature = "ui")]
     #[inline]
     pub fn ctx(self, key: &'static str) -> UiError {
         UiError::new(key)
@@ -58,6 +60,7 @@ impl UiError {
             .push_blp_match(self)
     }
 
+    #[cfg(feature = "ui")]
     #[inline]
     pub fn ctx_with(self, key: &'static str, f: impl FnOnce(UiError) -> UiError) -> UiError {
         f(UiError::new(key).push_blp_match(self))
@@ -65,6 +68,7 @@ impl UiError {
 
     // Helper to push a UiError as a Blp cause by converting it into a BlpError-like wrapper.
     // Here we simply embed the UiError's display into a synthetic BlpError key to preserve message.
+    #[cfg(feature = "ui")]
     fn push_blp_match(mut self, _ui: UiError) -> UiError {
         // Create a minimal BlpError with the UiError message
         let blp = blp::BlpError::new("ui-cause");
@@ -92,10 +96,11 @@ impl Error for UiError {
     fn source(&self) -> Option<&(dyn Error + 'static)> {
         self.causes
             .iter()
-            .find_map(|c| match c {
-                Cause::Blp(e) => Some(e as &dyn Error),
-                Cause::Std(e) => Some(e.as_ref()),
+            .map(|c| match c {
+                Cause::Blp(e) => e as &dyn Error,
+                Cause::Std(e) => e.as_ref(),
             })
+            .next()
     }
 }
 
@@ -0,0 +1,33 @@
+use crate::error::UiError;
+use crate::export::mip_chain::build_mip_chain;
+use blp::RgbaImage;
+use std::fs;
+use std::path::Path;
+
+/// Кодирует цепочку уровней в байты BLP (без записи на диск — для превью размера).
+pub fn encode_blp_bytes(mips: &[Option<RgbaImage>], quality: u8, mip_visible: &[bool; 16]) -> Result<Vec<u8>, blp::BlpError> {
+    let chain = build_mip_chain(mips).ok_or_else(|| blp::BlpError::new("error-save-no-image"))?;
+    blp::encode_blp(&chain, quality, mip_visible)
+}
+
+/// Кодирует цепочку уровней в BLP и пишет на диск.
+/// - `quality` — JPEG-качество (0..=100);
+/// - `mip_visible` — маска уровней: выключенные не попадают в файл.
+pub fn export_blp(path: &Path, mips: &[Option<RgbaImage>], quality: u8, mip_visible: &[bool; 16]) -> Result<(), UiError> {
+    if !mips.first().is_some_and(Option::is_some) {
+        return Err(UiError::new("error-save-no-image"));
+    }
+
+    let bytes = encode_blp_bytes(mips, quality, mip_visible).map_err(|e| {
+        UiError::new("error-save-blp")
+            .with_arg("path", path.to_string_lossy().into_owned())
+            .push_blp(e)
+    })?;
+
+    fs::write(path, bytes).map_err(|e| {
+        UiError::new("error-io-write-file")
+            .with_arg("path", path.to_string_lossy().into_owned())
+            .with_arg("msg", e.to_string())
+            .push_std(e)
+    })
+}
@@ -0,0 +1,98 @@
+use crate::error::UiError;
+use blp::RgbaImage;
+use blp::image::{ImageFormat, imageops};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+/// Что именно пишем в PNG.
+#[derive(Default, Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PngExportMode {
+    /// Только базовый уровень.
+    #[default]
+    Base,
+    /// Каждый видимый уровень отдельным файлом `name_mipNN.png`.
+    Mips,
+    /// Все видимые уровни одной вертикальной полосой (как в центральной панели).
+    Strip,
+}
+
+/// `dir/name.png` → `dir/name_mipNN.png`
+pub fn png_mip_path(path: &Path, i: usize) -> PathBuf {
+    let stem = path
+        .file_stem()
+        .map(|s| s.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    path.with_file_name(format!("{stem}_mip{i:02}.png"))
+}
+
+#[inline]
+fn visible_mips<'a>(mips: &'a [Option<RgbaImage>], mip_visible: &'a [bool; 16]) -> impl Iterator<Item = (usize, &'a RgbaImage)> {
+    mips.iter()
+        .enumerate()
+        .take(16)
+        .filter(|(i, _)| mip_visible[*i])
+        .filter_map(|(i, m)| m.as_ref().map(|m| (i, m)))
+}
+
+/// Список файлов, которые будут записаны (для тултипа и для самой записи).
+#[cfg(feature = "ui")]
+pub fn png_export_paths(path: &Path, mode: PngExportMode, mips: &[Option<RgbaImage>], mip_visible: &[bool; 16]) -> Vec<PathBuf> {
+    match mode {
+        PngExportMode::Base | PngExportMode::Strip => vec![path.to_path_buf()],
+        PngExportMode::Mips => visible_mips(mips, mip_visible)
+            .map(|(i, _)| png_mip_path(path, i))
+            .collect(),
+    }
+}
+
+/// Склеивает видимые уровни сверху вниз, выравнивая по левому краю.
+pub fn mip_strip(mips: &[Option<RgbaImage>], mip_visible: &[bool; 16]) -> Option<RgbaImage> {
+    let (w, h) = visible_mips(mips, mip_visible).fold((0, 0), |(w, h), (_, m)| (w.max(m.width()), h + m.height()));
+    if w == 0 || h == 0 {
+        return None;
+    }
+    let mut strip = RgbaImage::new(w, h);
+    let mut y = 0;
+    for (_, m) in visible_mips(mips, mip_visible) {
+        imageops::replace(&mut strip, m, 0, y as i64);
+        y += m.height();
+    }
+    Some(strip)
+}
+
+fn write_png(path: &Path, img: &RgbaImage) -> Result<(), UiError> {
+    img.save_with_format(path, ImageFormat::Png)
+        .map_err(|e| {
+            UiError::new("error-save-png")
+                .with_arg("path", path.to_string_lossy().into_owned())
+                .push_std(e)
+        })
+}
+
+/// Пишет PNG согласно режиму. Базовый уровень пишется всегда, независимо от видимости.
+pub fn export_png(path: &Path, mips: &[Option<RgbaImage>], mode: PngExportMode, mip_visible: &[bool; 16]) -> Result<(), UiError> {
+    match mode {
+        PngExportMode::Base => {
+            let base = mips
+                .first()
+                .and_then(|m| m.as_ref())
+                .ok_or_else(|| UiError::new("error-save-no-image"))?;
+            write_png(path, base)
+        }
+        PngExportMode::Mips => {
+            let mut written = 0;
+            for (i, m) in visible_mips(mips, mip_visible) {
+                write_png(&png_mip_path(path, i), m)?;
+                written += 1;
+            }
+            if written == 0 {
+                return Err(UiError::new("error-save-no-visible-mip"));
+            }
+            Ok(())
+        }
+        PngExportMode::Strip => {
+            let strip = mip_strip(mips, mip_visible).ok_or_else(|| UiError::new("error-save-no-visible-mip"))?;
+            write_png(path, &strip)
+        }
+    }
+}
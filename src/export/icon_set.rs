@@ -0,0 +1,239 @@
+use crate::error::UiError;
+use crate::export::export_blp::export_blp;
+use blp::RgbaImage;
+use blp::image::imageops::{self, FilterType};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Сторона иконки командной карты WC3.
+pub const ICON_SIZE: u32 = 64;
+
+/// Во сколько раз темнее неактивная (DIS*) иконка после обесцвечивания.
+const DISABLED_BRIGHTNESS: f32 = 0.5;
+
+/// Вариант иконки из набора WC3.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum IconVariant {
+    Btn,
+    DisBtn,
+    Pas,
+    DisPas,
+    Atc,
+    DisAtc,
+}
+
+impl IconVariant {
+    pub const ALL: [IconVariant; 6] = [
+        IconVariant::Btn,
+        IconVariant::DisBtn,
+        IconVariant::Pas,
+        IconVariant::DisPas,
+        IconVariant::Atc,
+        IconVariant::DisAtc,
+    ];
+
+    /// Префикс имени файла: `BTN`, `DISBTN`, `PASBTN`, …
+    pub fn prefix(self) -> &'static str {
+        match self {
+            IconVariant::Btn => "BTN",
+            IconVariant::DisBtn => "DISBTN",
+            IconVariant::Pas => "PASBTN",
+            IconVariant::DisPas => "DISPASBTN",
+            IconVariant::Atc => "ATCBTN",
+            IconVariant::DisAtc => "DISATCBTN",
+        }
+    }
+
+    /// Папка внутри `ReplaceableTextures`.
+    pub fn folder(self) -> &'static str {
+        match self {
+            IconVariant::Btn | IconVariant::Atc => "CommandButtons",
+            IconVariant::Pas => "PassiveButtons",
+            IconVariant::DisBtn | IconVariant::DisPas | IconVariant::DisAtc => "CommandButtonsDisabled",
+        }
+    }
+
+    fn disabled(self) -> bool {
+        matches!(self, IconVariant::DisBtn | IconVariant::DisPas | IconVariant::DisAtc)
+    }
+
+    fn frame(self) -> IconFrame {
+        match self {
+            IconVariant::Btn | IconVariant::DisBtn => IconFrame::Bevel,
+            IconVariant::Pas | IconVariant::DisPas => IconFrame::Passive,
+            IconVariant::Atc | IconVariant::DisAtc => IconFrame::Autocast,
+        }
+    }
+
+    /// `ReplaceableTextures/CommandButtons/BTNName.blp` относительно корня набора.
+    pub fn rel_path(self, name: &str) -> PathBuf {
+        Path::new("ReplaceableTextures")
+            .join(self.folder())
+            .join(format!("{}{name}.blp", self.prefix()))
+    }
+}
+
+#[derive(Clone, Copy)]
+enum IconFrame {
+    /// Стандартная фаска BTN: светлый верх/лево, тёмный низ/право.
+    Bevel,
+    /// Тёмная рамка пассивной способности.
+    Passive,
+    /// Золотая рамка автокаста.
+    Autocast,
+}
+
+/// Имя без известных префиксов: `BTNFootman` / `DISBTNFootman` → `Footman`.
+pub fn icon_base_name(stem: &str) -> &str {
+    let mut prefixes: Vec<&str> = IconVariant::ALL.iter().map(|v| v.prefix()).collect();
+    prefixes.sort_by_key(|p| std::cmp::Reverse(p.len()));
+    for p in prefixes {
+        if let (Some(head), Some(rest)) = (stem.get(..p.len()), stem.get(p.len()..))
+            && !rest.is_empty()
+            && head.eq_ignore_ascii_case(p)
+        {
+            return rest;
+        }
+    }
+    stem
+}
+
+/// Смешивание канала с целевым цветом: `t` — доля цели.
+#[inline]
+fn mix(c: u8, target: u8, t: f32) -> u8 {
+    (c as f32 + (target as f32 - c as f32) * t).round() as u8
+}
+
+fn apply_frame(img: &mut RgbaImage, frame: IconFrame) {
+    let (w, h) = img.dimensions();
+    for (x, y, px) in img.enumerate_pixels_mut() {
+        // расстояние до ближайшего края и сторона (верх/лево или низ/право)
+        let d_tl = x.min(y);
+        let d_br = (w - 1 - x).min(h - 1 - y);
+        let d = d_tl.min(d_br);
+        let top_left = d_tl <= d_br;
+
+        let (target, t): ([u8; 3], f32) = match frame {
+            IconFrame::Bevel => match d {
+                0 => ([0, 0, 0], 0.85),
+                1..=3 if top_left => ([255, 255, 255], 0.45 - 0.1 * d as f32),
+                1..=3 => ([0, 0, 0], 0.6 - 0.12 * d as f32),
+                _ => continue,
+            },
+            IconFrame::Passive => match d {
+                0 => ([0, 0, 0], 1.0),
+                1..=3 => ([40, 40, 40], 0.9),
+                4 => ([110, 110, 110], 0.7),
+                _ => continue,
+            },
+            IconFrame::Autocast => match d {
+                0 => ([60, 40, 0], 1.0),
+                1..=3 if top_left => ([255, 220, 90], 0.9),
+                1..=3 => ([200, 150, 30], 0.9),
+                4 => ([0, 0, 0], 0.5),
+                _ => continue,
+            },
+        };
+        for (c, target) in px.0.iter_mut().zip(target) {
+            *c = mix(*c, target, t);
+        }
+        px[3] = 255;
+    }
+}
+
+/// Неактивное состояние: обесцвечивание по яркости + затемнение.
+fn apply_disabled(img: &mut RgbaImage) {
+    for px in img.pixels_mut() {
+        let [r, g, b, _] = px.0;
+        let l = 0.299 * r as f32 + 0.587 * g as f32 + 0.114 * b as f32;
+        let v = (l * DISABLED_BRIGHTNESS).round() as u8;
+        px[0] = v;
+        px[1] = v;
+        px[2] = v;
+    }
+}
+
+/// Картинка одного варианта: 64×64, рамка, для DIS* — фильтр неактивности.
+pub fn icon_variant_image(src: &RgbaImage, variant: IconVariant) -> RgbaImage {
+    let mut img = if src.dimensions() == (ICON_SIZE, ICON_SIZE) {
+        src.clone()
+    } else {
+        imageops::resize(src, ICON_SIZE, ICON_SIZE, FilterType::Lanczos3)
+    };
+    apply_frame(&mut img, variant.frame());
+    if variant.disabled() {
+        apply_disabled(&mut img);
+    }
+    img
+}
+
+/// Пишет весь набор в `root` (папки создаются); возвращает записанные пути.
+pub fn export_icon_set(root: &Path, name: &str, src: &RgbaImage, quality: u8) -> Result<Vec<PathBuf>, UiError> {
+    let mut written = Vec::with_capacity(IconVariant::ALL.len());
+    for variant in IconVariant::ALL {
+        let path = root.join(variant.rel_path(name));
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir).map_err(|e| {
+                UiError::new("error-io-write-file")
+                    .with_arg("path", dir.to_string_lossy().into_owned())
+                    .with_arg("msg", e.to_string())
+                    .push_std(e)
+            })?;
+        }
+        let img = icon_variant_image(src, variant);
+        export_blp(&path, &[Some(img)], quality, &[true; 16])?;
+        written.push(path);
+    }
+    Ok(written)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use blp::image::Rgba;
+
+    #[test]
+    fn base_name_strips_prefixes() {
+        assert_eq!(icon_base_name("BTNFoo"), "Foo");
+        assert_eq!(icon_base_name("DISBTNFoo"), "Foo");
+        assert_eq!(icon_base_name("dispasbtnFoo"), "Foo");
+        assert_eq!(icon_base_name("ATCBTNFoo"), "Foo");
+        // одно имя-префикс не режется
+        assert_eq!(icon_base_name("BTN"), "BTN");
+        assert_eq!(icon_base_name("Foo"), "Foo");
+    }
+
+    #[test]
+    fn names_and_folders() {
+        let paths: Vec<PathBuf> = IconVariant::ALL.iter().map(|v| v.rel_path("Foo")).collect();
+        let expected = [
+            "ReplaceableTextures/CommandButtons/BTNFoo.blp",
+            "ReplaceableTextures/CommandButtonsDisabled/DISBTNFoo.blp",
+            "ReplaceableTextures/PassiveButtons/PASBTNFoo.blp",
+            "ReplaceableTextures/CommandButtonsDisabled/DISPASBTNFoo.blp",
+            "ReplaceableTextures/CommandButtons/ATCBTNFoo.blp",
+            "ReplaceableTextures/CommandButtonsDisabled/DISATCBTNFoo.blp",
+        ];
+        for (path, expected) in paths.iter().zip(expected) {
+            let expected: PathBuf = expected.split('/').collect();
+            assert_eq!(*path, expected);
+        }
+    }
+
+    #[test]
+    fn variant_images() {
+        let src = RgbaImage::from_pixel(32, 32, Rgba([200, 40, 10, 255]));
+        for variant in IconVariant::ALL {
+            let img = icon_variant_image(&src, variant);
+            assert_eq!(img.dimensions(), (ICON_SIZE, ICON_SIZE));
+            let grey = img.pixels().all(|p| p[0] == p[1] && p[1] == p[2]);
+            assert_eq!(grey, variant.disabled(), "{variant:?}");
+        }
+
+        // центр без рамки: яркость × DISABLED_BRIGHTNESS
+        let dis = icon_variant_image(&src, IconVariant::DisBtn);
+        let l = 0.299 * 200.0 + 0.587 * 40.0 + 0.114 * 10.0;
+        let v = (l * DISABLED_BRIGHTNESS).round() as u8;
+        assert_eq!(dis.get_pixel(32, 32).0, [v, v, v, 255]);
+    }
+}
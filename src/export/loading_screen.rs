@@ -0,0 +1,190 @@
+use crate::error::UiError;
+use crate::export::export_blp::encode_blp_bytes;
+use blp::image::Rgba;
+use blp::image::imageops::{self, FilterType};
+use blp::{AnyImage, BlpError, RgbaImage};
+use std::fmt::Write as _;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Размер экрана загрузки WC3 (4:3), в который вписывается исходник.
+pub const SCREEN_W: u32 = 1024;
+pub const SCREEN_H: u32 = 768;
+/// Сторона тайла: степень двойки, которую движок берёт без пересчёта.
+pub const TILE: u32 = 512;
+
+/// Суффиксы тайлов сетки 2×2 — как в стандартной модели экрана загрузки.
+const SUFFIX: [[&str; 2]; 2] = [["TL", "TR"], ["BL", "BR"]];
+
+/// Один тайл: имя файла и занятая им область экрана.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LoadingTile {
+    pub file: String,
+    pub x: u32,
+    pub y: u32,
+    /// Полезная часть тайла (остаток текстуры `TILE×TILE` прозрачен).
+    pub w: u32,
+    pub h: u32,
+}
+
+impl LoadingTile {
+    /// UV полезной области внутри текстуры: `[u0, v0, u1, v1]`.
+    pub fn uv(&self) -> [f32; 4] {
+        [0.0, 0.0, self.w as f32 / TILE as f32, self.h as f32 / TILE as f32]
+    }
+}
+
+/// Раскладка экрана на тайлы (построчно, сверху вниз).
+pub fn loading_screen_tiles(name: &str) -> Vec<LoadingTile> {
+    let mut tiles = Vec::new();
+    for (row, y) in (0..SCREEN_H).step_by(TILE as usize).enumerate() {
+        for (col, x) in (0..SCREEN_W).step_by(TILE as usize).enumerate() {
+            tiles.push(LoadingTile {
+                file: format!("{name}{}.blp", SUFFIX[row][col]),
+                x,
+                y,
+                w: TILE.min(SCREEN_W - x),
+                h: TILE.min(SCREEN_H - y),
+            });
+        }
+    }
+    tiles
+}
+
+/// Исходник → ровно `SCREEN_W×SCREEN_H`: вписывается без искажения пропорций, поля — чёрные.
+pub fn fit_screen(src: &RgbaImage) -> RgbaImage {
+    let (w, h) = src.dimensions();
+    if (w, h) == (SCREEN_W, SCREEN_H) {
+        return src.clone();
+    }
+    let scale = (SCREEN_W as f64 / w as f64).min(SCREEN_H as f64 / h as f64);
+    let fw = ((w as f64 * scale).round() as u32).clamp(1, SCREEN_W);
+    let fh = ((h as f64 * scale).round() as u32).clamp(1, SCREEN_H);
+    let fitted = imageops::resize(src, fw, fh, FilterType::Lanczos3);
+
+    let mut out = RgbaImage::from_pixel(SCREEN_W, SCREEN_H, Rgba([0, 0, 0, 255]));
+    imageops::replace(&mut out, &fitted, ((SCREEN_W - fw) / 2) as i64, ((SCREEN_H - fh) / 2) as i64);
+    out
+}
+
+/// Вырезает тайл в текстуру `TILE×TILE` (полезная часть — в левом верхнем углу).
+pub fn tile_image(screen: &RgbaImage, tile: &LoadingTile) -> RgbaImage {
+    let mut out = RgbaImage::new(TILE, TILE);
+    let part = imageops::crop_imm(screen, tile.x, tile.y, tile.w, tile.h).to_image();
+    imageops::replace(&mut out, &part, 0, 0);
+    out
+}
+
+/// Закодированный тайл + то, что из него прочитает игра.
+pub struct EncodedTile {
+    pub tile: LoadingTile,
+    pub bytes: Vec<u8>,
+    pub decoded: RgbaImage,
+}
+
+/// Режет, кодирует каждый тайл в BLP и декодирует обратно (для честного превью).
+pub fn encode_loading_screen(src: &RgbaImage, name: &str, quality: u8) -> Result<Vec<EncodedTile>, BlpError> {
+    let screen = fit_screen(src);
+    loading_screen_tiles(name)
+        .into_iter()
+        .map(|tile| {
+            let img = tile_image(&screen, &tile);
+            let bytes = encode_blp_bytes(&[Some(img)], quality, &[true; 16])?;
+            let decoded = AnyImage::from_buffer(&bytes)?.to_rgba()?;
+            Ok(EncodedTile { tile, bytes, decoded })
+        })
+        .collect()
+}
+
+/// Собирает экран обратно из декодированных тайлов.
+pub fn reassemble(tiles: &[EncodedTile]) -> RgbaImage {
+    let mut out = RgbaImage::new(SCREEN_W, SCREEN_H);
+    for t in tiles {
+        let part = imageops::crop_imm(&t.decoded, 0, 0, t.tile.w, t.tile.h).to_image();
+        imageops::replace(&mut out, &part, t.tile.x as i64, t.tile.y as i64);
+    }
+    out
+}
+
+/// Манифест: раскладка тайлов с UV и блок `Textures` для MDL модели экрана загрузки.
+pub fn loading_screen_manifest(tiles: &[LoadingTile]) -> String {
+    let mut s = String::new();
+    let _ = writeln!(s, "# WC3 loading screen {SCREEN_W}x{SCREEN_H}, tile {TILE}x{TILE}");
+    let _ = writeln!(s, "# file\tx\ty\tw\th\tu0\tv0\tu1\tv1");
+    for t in tiles {
+        let [u0, v0, u1, v1] = t.uv();
+        let _ = writeln!(s, "{}\t{}\t{}\t{}\t{}\t{u0:.4}\t{v0:.4}\t{u1:.4}\t{v1:.4}", t.file, t.x, t.y, t.w, t.h);
+    }
+    let _ = writeln!(s);
+    let _ = writeln!(s, "# MDL Textures block; prefix paths with the import folder if needed");
+    let _ = writeln!(s, "Textures {} {{", tiles.len());
+    for t in tiles {
+        let _ = writeln!(s, "\tBitmap {{\n\t\tImage \"{}\",\n\t}}", t.file);
+    }
+    let _ = writeln!(s, "}}");
+    s
+}
+
+/// Пишет тайлы и манифест `name.txt` в `dir`; возвращает записанные пути.
+pub fn export_loading_screen(dir: &Path, name: &str, tiles: &[EncodedTile]) -> Result<Vec<PathBuf>, UiError> {
+    let write = |path: PathBuf, bytes: &[u8]| {
+        fs::write(&path, bytes).map_err(|e| {
+            UiError::new("error-io-write-file")
+                .with_arg("path", path.to_string_lossy().into_owned())
+                .with_arg("msg", e.to_string())
+                .push_std(e)
+        })?;
+        Ok::<_, UiError>(path)
+    };
+
+    let mut written = Vec::with_capacity(tiles.len() + 1);
+    for t in tiles {
+        written.push(write(dir.join(&t.tile.file), &t.bytes)?);
+    }
+    let layout: Vec<LoadingTile> = tiles.iter().map(|t| t.tile.clone()).collect();
+    written.push(write(dir.join(format!("{name}.txt")), loading_screen_manifest(&layout).as_bytes())?);
+    Ok(written)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RED: Rgba<u8> = Rgba([255, 0, 0, 255]);
+    const BLACK: Rgba<u8> = Rgba([0, 0, 0, 255]);
+
+    #[test]
+    fn fit_screen_letterboxes_wide() {
+        // 16:9 → полосы сверху и снизу
+        let screen = fit_screen(&RgbaImage::from_pixel(160, 90, RED));
+        assert_eq!(screen.dimensions(), (SCREEN_W, SCREEN_H));
+        assert_eq!(*screen.get_pixel(0, 0), BLACK);
+        assert_eq!(*screen.get_pixel(SCREEN_W / 2, SCREEN_H - 1), BLACK);
+        assert_eq!(*screen.get_pixel(0, SCREEN_H / 2), RED);
+        assert_eq!(*screen.get_pixel(SCREEN_W - 1, SCREEN_H / 2), RED);
+    }
+
+    #[test]
+    fn fit_screen_pillarboxes_tall() {
+        let screen = fit_screen(&RgbaImage::from_pixel(100, 200, RED));
+        assert_eq!(*screen.get_pixel(0, SCREEN_H / 2), BLACK);
+        assert_eq!(*screen.get_pixel(SCREEN_W / 2, 0), RED);
+        assert_eq!(*screen.get_pixel(SCREEN_W / 2, SCREEN_H - 1), RED);
+    }
+
+    #[test]
+    fn fit_screen_keeps_4_3_full() {
+        let screen = fit_screen(&RgbaImage::from_pixel(400, 300, RED));
+        assert!(screen.pixels().all(|p| *p == RED));
+    }
+
+    #[test]
+    fn tiles_cover_screen() {
+        let tiles = loading_screen_tiles("Load");
+        let names: Vec<&str> = tiles.iter().map(|t| t.file.as_str()).collect();
+        assert_eq!(names, ["LoadTL.blp", "LoadTR.blp", "LoadBL.blp", "LoadBR.blp"]);
+        let area: u32 = tiles.iter().map(|t| t.w * t.h).sum();
+        assert_eq!(area, SCREEN_W * SCREEN_H);
+        assert_eq!(tiles[3].uv(), [0.0, 0.0, 1.0, 0.5]);
+    }
+}
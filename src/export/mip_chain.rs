@@ -0,0 +1,46 @@
+use blp::RgbaImage;
+use blp::image::imageops::{self, FilterType};
+
+/// Максимум уровней в BLP.
+pub const MAX_MIPS: usize = 16;
+
+/// Размер уровня `i` для базы `w×h` (не меньше 1×1).
+#[inline]
+pub fn mip_dims(w: u32, h: u32, i: usize) -> (u32, u32) {
+    ((w >> i).max(1), (h >> i).max(1))
+}
+
+/// Сколько уровней нужно, чтобы дойти до 1×1 (но не больше 16).
+#[inline]
+pub fn mip_count(w: u32, h: u32) -> usize {
+    let max = w.max(h).max(1);
+    ((32 - max.leading_zeros()) as usize).min(MAX_MIPS)
+}
+
+/// Полная цепочка уровней от базового до 1×1.
+/// Существующие уровни с правильным размером берутся как есть,
+/// недостающие — досчитываются из предыдущего.
+pub fn build_mip_chain(mips: &[Option<RgbaImage>]) -> Option<Vec<RgbaImage>> {
+    build_mip_chain_with(mips, |_, _| {})
+}
+
+/// То же, с отчётом `on_level(n, of)` после каждого готового уровня (1-based).
+pub fn build_mip_chain_with(mips: &[Option<RgbaImage>], mut on_level: impl FnMut(usize, usize)) -> Option<Vec<RgbaImage>> {
+    let base = mips.first()?.as_ref()?;
+    let (w, h) = base.dimensions();
+    let count = mip_count(w, h);
+
+    let mut chain: Vec<RgbaImage> = Vec::with_capacity(count);
+    chain.push(base.clone());
+    on_level(1, count);
+    for i in 1..count {
+        let (mw, mh) = mip_dims(w, h, i);
+        let level = match mips.get(i).and_then(|m| m.as_ref()) {
+            Some(m) if m.dimensions() == (mw, mh) => m.clone(),
+            _ => imageops::resize(&chain[i - 1], mw, mh, FilterType::Triangle),
+        };
+        chain.push(level);
+        on_level(i + 1, count);
+    }
+    Some(chain)
+}
@@ -0,0 +1,315 @@
+use crate::export::mip_chain::{mip_count, mip_dims};
+use blp::RgbaImage;
+use std::f32::consts::PI;
+
+/// Ядро для построения уровней.
+#[derive(Default, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MipFilter {
+    /// Среднее по 2×2 — резко, но с лесенкой на диагоналях.
+    Box,
+    /// Линейный тент (как `FilterType::Triangle` по умолчанию).
+    #[default]
+    Triangle,
+    Lanczos3,
+    /// Окно Кайзера (α = 4, ширина 3) — мягче Lanczos, почти без звона.
+    Kaiser,
+}
+
+impl MipFilter {
+    pub const ALL: [MipFilter; 4] = [MipFilter::Box, MipFilter::Triangle, MipFilter::Lanczos3, MipFilter::Kaiser];
+
+    /// Радиус ядра в пикселях уровня-источника (при сжатии в 2 раза растягивается вдвое).
+    fn support(self) -> f32 {
+        match self {
+            MipFilter::Box => 0.5,
+            MipFilter::Triangle => 1.0,
+            MipFilter::Lanczos3 | MipFilter::Kaiser => 3.0,
+        }
+    }
+
+    fn weight(self, x: f32) -> f32 {
+        let x = x.abs();
+        match self {
+            MipFilter::Box => {
+                if x <= 0.5 { 1.0 } else { 0.0 }
+            }
+            MipFilter::Triangle => (1.0 - x).max(0.0),
+            MipFilter::Lanczos3 => {
+                if x < 3.0 { sinc(x) * sinc(x / 3.0) } else { 0.0 }
+            }
+            MipFilter::Kaiser => {
+                const ALPHA: f32 = 4.0;
+                const WIDTH: f32 = 3.0;
+                if x < WIDTH {
+                    let t = x / WIDTH;
+                    sinc(x) * bessel_i0(ALPHA * (1.0 - t * t).sqrt()) / bessel_i0(ALPHA)
+                } else {
+                    0.0
+                }
+            }
+        }
+    }
+}
+
+/// Как строить цепочку мипов при экспорте.
+#[derive(Default, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct MipGenSettings {
+    pub filter: MipFilter,
+    /// Усреднять в линейном свете (sRGB → linear → sRGB), без потемнения на контрастных краях.
+    pub gamma_correct: bool,
+    /// Сохранять долю пикселей с альфой выше порога (листва/решётки не «тают» вдали).
+    pub preserve_coverage: bool,
+}
+
+/// Порог альфа-теста для сохранения покрытия.
+const COVERAGE_CUTOFF: f32 = 0.5;
+
+#[inline]
+fn sinc(x: f32) -> f32 {
+    if x.abs() < 1e-6 {
+        1.0
+    } else {
+        let px = PI * x;
+        px.sin() / px
+    }
+}
+
+/// Модифицированная функция Бесселя I₀ (ряд; для α ≤ 4 хватает 20 членов).
+fn bessel_i0(x: f32) -> f32 {
+    let (mut sum, mut term) = (1.0f32, 1.0f32);
+    let q = x * x / 4.0;
+    for k in 1..20 {
+        term *= q / (k * k) as f32;
+        sum += term;
+    }
+    sum
+}
+
+#[inline]
+fn srgb_to_linear(c: f32) -> f32 {
+    if c <= 0.04045 { c / 12.92 } else { ((c + 0.055) / 1.055).powf(2.4) }
+}
+
+#[inline]
+fn linear_to_srgb(c: f32) -> f32 {
+    if c <= 0.0031308 { c * 12.92 } else { 1.055 * c.powf(1.0 / 2.4) - 0.055 }
+}
+
+/// Уровень в float: RGB домножены на альфу (без ореолов от прозрачных пикселей).
+struct Plane {
+    w: u32,
+    h: u32,
+    px: Vec<[f32; 4]>,
+}
+
+impl Plane {
+    fn from_rgba(img: &RgbaImage, gamma: bool) -> Self {
+        let px = img
+            .pixels()
+            .map(|p| {
+                let a = p[3] as f32 / 255.0;
+                let c = |v: u8| {
+                    let v = v as f32 / 255.0;
+                    (if gamma { srgb_to_linear(v) } else { v }) * a
+                };
+                [c(p[0]), c(p[1]), c(p[2]), a]
+            })
+            .collect();
+        Self { w: img.width(), h: img.height(), px }
+    }
+
+    /// Обратно в 8 бит; `alpha_scale` — множитель альфы для сохранения покрытия.
+    fn to_rgba(&self, gamma: bool, alpha_scale: f32) -> RgbaImage {
+        let mut out = RgbaImage::new(self.w, self.h);
+        for (dst, [r, g, b, a]) in out.pixels_mut().zip(&self.px) {
+            let a = a.clamp(0.0, 1.0);
+            let c = |v: f32| {
+                let v = if a > 0.0 { (v / a).clamp(0.0, 1.0) } else { 0.0 };
+                let v = if gamma { linear_to_srgb(v) } else { v };
+                (v * 255.0).round() as u8
+            };
+            dst.0 = [c(*r), c(*g), c(*b), ((a * alpha_scale).clamp(0.0, 1.0) * 255.0).round() as u8];
+        }
+        out
+    }
+
+    fn coverage(&self, alpha_scale: f32) -> f32 {
+        if self.px.is_empty() {
+            return 0.0;
+        }
+        let n = self
+            .px
+            .iter()
+            .filter(|p| p[3] * alpha_scale >= COVERAGE_CUTOFF)
+            .count();
+        n as f32 / self.px.len() as f32
+    }
+
+    /// Наименьший множитель альфы, при котором покрытие не ниже `target` (бинарный поиск).
+    /// Если покрытие уже совпадает (с точностью до пикселя) — альфа не трогается.
+    fn coverage_scale(&self, target: f32) -> f32 {
+        let current = self.coverage(1.0);
+        if (current - target).abs() <= 1.0 / self.px.len().max(1) as f32 {
+            return 1.0;
+        }
+        let (mut lo, mut hi) = if current < target { (1.0f32, 4.0f32) } else { (0.0f32, 1.0f32) };
+        for _ in 0..16 {
+            let mid = (lo + hi) / 2.0;
+            if self.coverage(mid) < target { lo = mid } else { hi = mid }
+        }
+        hi
+    }
+}
+
+/// Веса одномерной передискретизации `src_len → dst_len`: для каждого выхода — (первый индекс, веса).
+fn weights(filter: MipFilter, src_len: u32, dst_len: u32) -> Vec<(usize, Vec<f32>)> {
+    let scale = src_len as f32 / dst_len as f32;
+    let stretch = scale.max(1.0);
+    let support = filter.support() * stretch;
+    (0..dst_len)
+        .map(|i| {
+            let center = (i as f32 + 0.5) * scale;
+            let left = (center - support).floor().max(0.0) as usize;
+            let right = ((center + support).ceil() as usize).min(src_len as usize);
+            let mut ws: Vec<f32> = (left..right)
+                .map(|j| filter.weight((j as f32 + 0.5 - center) / stretch))
+                .collect();
+            let sum: f32 = ws.iter().sum();
+            if sum.abs() > 1e-6 {
+                ws.iter_mut().for_each(|w| *w /= sum);
+            }
+            (left, ws)
+        })
+        .collect()
+}
+
+/// Раздельная (сначала по X, потом по Y) передискретизация.
+fn resample(src: &Plane, w: u32, h: u32, filter: MipFilter) -> Plane {
+    let wx = weights(filter, src.w, w);
+    let wy = weights(filter, src.h, h);
+
+    let mut tmp = vec![[0.0f32; 4]; (w * src.h) as usize];
+    for y in 0..src.h as usize {
+        let row = &src.px[y * src.w as usize..(y + 1) * src.w as usize];
+        for (x, (left, ws)) in wx.iter().enumerate() {
+            let mut acc = [0.0f32; 4];
+            for (k, wgt) in ws.iter().enumerate() {
+                let p = row[left + k];
+                for (a, v) in acc.iter_mut().zip(p) {
+                    *a += v * wgt;
+                }
+            }
+            tmp[y * w as usize + x] = acc;
+        }
+    }
+
+    let mut px = vec![[0.0f32; 4]; (w * h) as usize];
+    for (y, (top, ws)) in wy.iter().enumerate() {
+        for x in 0..w as usize {
+            let mut acc = [0.0f32; 4];
+            for (k, wgt) in ws.iter().enumerate() {
+                let p = tmp[(top + k) * w as usize + x];
+                for (a, v) in acc.iter_mut().zip(p) {
+                    *a += v * wgt;
+                }
+            }
+            px[y * w as usize + x] = acc;
+        }
+    }
+    Plane { w, h, px }
+}
+
+/// Полная цепочка от базового уровня до 1×1 по настройкам (каждый уровень — из предыдущего, в float).
+pub fn generate_mip_chain(base: &RgbaImage, settings: MipGenSettings) -> Vec<RgbaImage> {
+    let (w, h) = base.dimensions();
+    let count = mip_count(w, h);
+    let gamma = settings.gamma_correct;
+
+    let mut chain = Vec::with_capacity(count);
+    chain.push(base.clone());
+
+    let mut plane = Plane::from_rgba(base, gamma);
+    let target = settings
+        .preserve_coverage
+        .then(|| plane.coverage(1.0));
+    for i in 1..count {
+        let (mw, mh) = mip_dims(w, h, i);
+        plane = resample(&plane, mw, mh, settings.filter);
+        let alpha_scale = target.map_or(1.0, |t| plane.coverage_scale(t));
+        chain.push(plane.to_rgba(gamma, alpha_scale));
+    }
+    chain
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use blp::image::Rgba;
+
+    #[test]
+    fn weights_are_normalized() {
+        for filter in MipFilter::ALL {
+            for (src, dst) in [(64, 32), (7, 3), (2, 1), (1, 1)] {
+                for (left, ws) in weights(filter, src, dst) {
+                    let sum: f32 = ws.iter().sum();
+                    assert!((sum - 1.0).abs() < 1e-4, "{filter:?} {src}→{dst}: {sum}");
+                    assert!(left + ws.len() <= src as usize);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn box_halving_averages_pairs() {
+        let ws = weights(MipFilter::Box, 8, 4);
+        for (i, (left, w)) in ws.iter().enumerate() {
+            assert_eq!(*left, 2 * i);
+            assert_eq!(w, &[0.5, 0.5]);
+        }
+    }
+
+    #[test]
+    fn chain_dims_and_count() {
+        let base = RgbaImage::from_pixel(64, 16, Rgba([200, 100, 50, 255]));
+        let chain = generate_mip_chain(&base, MipGenSettings::default());
+        assert_eq!(chain.len(), mip_count(64, 16));
+        for (i, mip) in chain.iter().enumerate() {
+            assert_eq!(mip.dimensions(), mip_dims(64, 16, i));
+        }
+        assert_eq!(chain.last().unwrap().dimensions(), (1, 1));
+    }
+
+    #[test]
+    fn solid_and_opaque_stay_so() {
+        let base = RgbaImage::from_pixel(32, 32, Rgba([200, 100, 50, 255]));
+        for filter in MipFilter::ALL {
+            for gamma_correct in [false, true] {
+                let settings = MipGenSettings { filter, gamma_correct, preserve_coverage: false };
+                for mip in generate_mip_chain(&base, settings) {
+                    assert!(mip.pixels().all(|p| p.0 == [200, 100, 50, 255]), "{settings:?}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn coverage_is_preserved() {
+        // «Листва»: ~30% непрозрачных пикселей среди полупрозрачных — без коррекции уровни тают ниже порога
+        let mut seed = 1u32;
+        let base = RgbaImage::from_fn(64, 64, |_, _| {
+            seed = seed.wrapping_mul(1_103_515_245).wrapping_add(12_345);
+            let r = (seed >> 16) % 100;
+            Rgba([0, 255, 0, if r < 30 { 255 } else { (r / 2) as u8 }])
+        });
+        let coverage = |img: &RgbaImage| img.pixels().filter(|p| p[3] as f32 / 255.0 >= COVERAGE_CUTOFF).count() as f32 / img.pixels().len() as f32;
+        let target = coverage(&base);
+
+        let plain = generate_mip_chain(&base, MipGenSettings::default());
+        assert!(coverage(&plain[3]) < target / 2.0, "{}", coverage(&plain[3]));
+
+        let kept = generate_mip_chain(&base, MipGenSettings { preserve_coverage: true, ..Default::default() });
+        for mip in &kept[1..5] {
+            assert!((coverage(mip) - target).abs() <= 0.05, "{} vs {target}", coverage(mip));
+        }
+    }
+}
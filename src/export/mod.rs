@@ -0,0 +1,11 @@
+pub mod export_blp;
+pub mod export_png;
+#[cfg(feature = "ui")]
+pub mod icon_set;
+#[cfg(feature = "ui")]
+pub mod loading_screen;
+pub mod mip_chain;
+#[cfg(feature = "ui")]
+pub mod mip_filter;
+#[cfg(feature = "ui")]
+pub mod pot_resize;
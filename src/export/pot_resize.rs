@@ -0,0 +1,141 @@
+use crate::export::mip_chain::mip_count;
+use blp::RgbaImage;
+use blp::image::imageops::{self, FilterType};
+
+/// Варианты стороны для явного выбора: 1…4096.
+pub const POT_SIDES: [u32; 13] = [1, 2, 4, 8, 16, 32, 64, 128, 256, 512, 1024, 2048, 4096];
+
+/// Куда приводить размер.
+#[derive(Default, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PotTarget {
+    /// Ближайшая степень двойки сверху (по каждой стороне).
+    #[default]
+    Larger,
+    /// Ближайшая степень двойки снизу.
+    Smaller,
+    /// Заданные вручную `w×h`.
+    Explicit(u32, u32),
+}
+
+/// Как получить новый размер.
+#[derive(Default, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PotMethod {
+    #[default]
+    Lanczos3,
+    Bilinear,
+    Nearest,
+    /// Без масштабирования: холст прозрачными пикселями (или обрезка), картинка в левом верхнем углу.
+    Pad,
+}
+
+impl PotMethod {
+    pub const ALL: [PotMethod; 4] = [PotMethod::Lanczos3, PotMethod::Bilinear, PotMethod::Nearest, PotMethod::Pad];
+}
+
+/// Настройки приведения к степени двойки.
+#[derive(Default, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PotResize {
+    pub target: PotTarget,
+    pub method: PotMethod,
+}
+
+#[inline]
+pub fn is_pot(n: u32) -> bool {
+    n.is_power_of_two()
+}
+
+/// Ближайшая степень двойки `>= n` (не меньше 1).
+#[inline]
+pub fn pot_larger(n: u32) -> u32 {
+    n.max(1).next_power_of_two()
+}
+
+/// Ближайшая степень двойки `<= n` (не меньше 1).
+#[inline]
+pub fn pot_smaller(n: u32) -> u32 {
+    1 << (31 - n.max(1).leading_zeros())
+}
+
+impl PotResize {
+    /// Итоговый размер для `w×h`.
+    pub fn dims(&self, w: u32, h: u32) -> (u32, u32) {
+        match self.target {
+            PotTarget::Larger => (pot_larger(w), pot_larger(h)),
+            PotTarget::Smaller => (pot_smaller(w), pot_smaller(h)),
+            PotTarget::Explicit(tw, th) => (tw.max(1), th.max(1)),
+        }
+    }
+
+    /// Применяет к изображению; размер уже подходящий — `None`.
+    pub fn apply(&self, img: &RgbaImage) -> Option<RgbaImage> {
+        let (w, h) = img.dimensions();
+        let (tw, th) = self.dims(w, h);
+        if (tw, th) == (w, h) {
+            return None;
+        }
+        let filter = match self.method {
+            PotMethod::Lanczos3 => FilterType::Lanczos3,
+            PotMethod::Bilinear => FilterType::Triangle,
+            PotMethod::Nearest => FilterType::Nearest,
+            PotMethod::Pad => {
+                let mut out = RgbaImage::new(tw, th);
+                imageops::replace(&mut out, img, 0, 0);
+                return Some(out);
+            }
+        };
+        Some(imageops::resize(img, tw, th, filter))
+    }
+}
+
+/// Маска уровней для цепочки нового размера `w×h`: выключенными остаются только те,
+/// что пользователь выключил у старой цепочки (`existed[i]` и не `visible[i]`), лишних нет.
+pub fn pot_mip_mask(w: u32, h: u32, visible: &[bool; 16], existed: &[bool; 16]) -> [bool; 16] {
+    let count = mip_count(w, h);
+    std::array::from_fn(|i| i < count && (visible[i] || !existed[i]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pot_rounding() {
+        assert_eq!((pot_larger(0), pot_larger(1), pot_larger(100), pot_larger(128)), (1, 1, 128, 128));
+        assert_eq!((pot_smaller(0), pot_smaller(1), pot_smaller(100), pot_smaller(128)), (1, 1, 64, 128));
+    }
+
+    #[test]
+    fn dims_by_target() {
+        let mut s = PotResize::default();
+        assert_eq!(s.dims(100, 60), (128, 64));
+        s.target = PotTarget::Smaller;
+        assert_eq!(s.dims(100, 60), (64, 32));
+        s.target = PotTarget::Explicit(256, 0);
+        assert_eq!(s.dims(100, 60), (256, 1));
+    }
+
+    #[test]
+    fn apply_pads_top_left() {
+        let img = RgbaImage::from_pixel(3, 2, blp::image::Rgba([9, 9, 9, 255]));
+        let s = PotResize { method: PotMethod::Pad, ..Default::default() };
+        let out = s.apply(&img).unwrap();
+        assert_eq!(out.dimensions(), (4, 2));
+        assert_eq!(out.get_pixel(2, 1).0, [9, 9, 9, 255]);
+        assert_eq!(out.get_pixel(3, 0).0, [0, 0, 0, 0]);
+        assert!(s.apply(&out).is_none());
+    }
+
+    #[test]
+    fn mip_mask_for_new_chain() {
+        // 100×60: 7 уровней; пользователь выключил 2-й, 7-й у файла отсутствовал
+        let mut visible = [true; 16];
+        let mut existed = [false; 16];
+        existed[..6].fill(true);
+        visible[2] = false;
+        visible[6] = false;
+        // 128×64 → 8 уровней
+        let mask = pot_mip_mask(128, 64, &visible, &existed);
+        let expected: [bool; 16] = std::array::from_fn(|i| i < 8 && i != 2);
+        assert_eq!(mask, expected);
+    }
+}
@@ -5,6 +5,7 @@ use crate::error::UiError;
 pub trait EnsureReadable {
     /// Проверить, что путь указывает на **читаемый обычный файл**.
     /// - Ошибки точные: файл не найден / нет прав / это каталог / не обычный файл / битый путь / ошибка метаданных.
+    #[cfg(feature = "ui")]
     fn ensure_readable(&self) -> Result<(), UiError>;
     
     /// Прочитать содержимое файла (одно открытие).
@@ -13,6 +14,7 @@ pub trait EnsureReadable {
 }
 
 impl EnsureReadable for Path {
+    #[cfg(feature = "ui")]
     fn ensure_readable(&self) -> Result<(), UiError> {
         // Проверяем метаданные (без открытия) — различаем типы объекта и базовые причины.
         match fs::symlink_metadata(self) {
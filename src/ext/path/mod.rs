@@ -1,2 +1,3 @@
 pub mod ensure_readable;
+#[cfg(feature = "ui")]
 pub mod to_abs_string_with_macros;
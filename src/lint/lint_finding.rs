@@ -0,0 +1,43 @@
+/// Насколько серьёзна находка: `Error` — игра, скорее всего, текстуру не примет.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum LintSeverity {
+    Warning,
+    Error,
+}
+
+impl LintSeverity {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            LintSeverity::Warning => "warning",
+            LintSeverity::Error => "error",
+        }
+    }
+}
+
+/// Одна находка линтера. `code` — стабильный идентификатор для CI,
+/// ключ локализации — `lint-<code>`, аргументы подставляются в перевод.
+#[derive(Clone, Debug)]
+pub struct LintFinding {
+    pub severity: LintSeverity,
+    pub code: &'static str,
+    pub args: Vec<(&'static str, String)>,
+}
+
+impl LintFinding {
+    #[inline]
+    pub fn new(severity: LintSeverity, code: &'static str) -> Self {
+        Self { severity, code, args: Vec::new() }
+    }
+
+    #[inline]
+    pub fn with_arg(mut self, name: &'static str, val: impl ToString) -> Self {
+        self.args.push((name, val.to_string()));
+        self
+    }
+
+    #[cfg(feature = "ui")]
+    #[inline]
+    pub fn locale_key(&self) -> String {
+        format!("lint-{}", self.code)
+    }
+}
@@ -0,0 +1,353 @@
+use crate::decode::blp_info::{BlpCompression, BlpInfo, BlpVersion, HEADER_BLP1, HEADER_BLP2};
+use crate::export::mip_chain::{mip_count, mip_dims};
+use crate::lint::lint_finding::{LintFinding, LintSeverity};
+use blp::RgbaImage;
+use std::cmp::Reverse;
+
+use LintSeverity::{Error, Warning};
+
+/// Больше этого WC3 грузит с пережатием/артефактами; в карты лучше не класть.
+pub const MAX_DIM: u32 = 1024;
+/// Классический движок WC3 не читает общий JPEG-заголовок длиннее этого.
+pub const MAX_JPEG_HEADER: u32 = 624;
+
+/// Проверки открытой текстуры: заголовок BLP (если есть) + декодированные уровни.
+pub fn lint_texture(info: Option<&BlpInfo>, mips: &[Option<RgbaImage>]) -> Vec<LintFinding> {
+    let mut out = Vec::new();
+
+    let Some(base) = mips.first().and_then(|m| m.as_ref()) else {
+        out.push(LintFinding::new(Error, "no-image"));
+        return out;
+    };
+    let (w, h) = base.dimensions();
+
+    if !w.is_power_of_two() || !h.is_power_of_two() {
+        out.push(LintFinding::new(Error, "npot").with_arg("width", w).with_arg("height", h));
+    }
+    if w.max(h) > MAX_DIM {
+        out.push(
+            LintFinding::new(Warning, "oversized")
+                .with_arg("width", w)
+                .with_arg("height", h)
+                .with_arg("max", MAX_DIM),
+        );
+    }
+
+    let alpha = alpha_stats(base);
+    if alpha.all_transparent {
+        out.push(LintFinding::new(Warning, "alpha-all-transparent"));
+    }
+
+    if let Some(info) = info {
+        lint_header(info, alpha.opaque, &mut out);
+    }
+
+    out.sort_by_key(|f| Reverse(f.severity));
+    out
+}
+
+/// Маска видимых мипов, с которой будет записан BLP: дырки и недостающий хвост.
+#[cfg(feature = "ui")]
+pub fn lint_export_mask(w: u32, h: u32, mask: &[bool; 16]) -> Vec<LintFinding> {
+    let mut out = Vec::new();
+    let expected = mip_count(w, h);
+
+    if !mask[0] {
+        out.push(LintFinding::new(Error, "export-no-base"));
+        return out;
+    }
+    if let Some(gap) = (1..expected).find(|&i| !mask[i] && mask[i + 1..expected].iter().any(|v| *v)) {
+        out.push(LintFinding::new(Error, "export-mips-gap").with_arg("mip", gap));
+    }
+    let present = mask[..expected].iter().filter(|v| **v).count();
+    if present < expected {
+        out.push(
+            LintFinding::new(Warning, "export-mips-truncated")
+                .with_arg("present", present)
+                .with_arg("expected", expected),
+        );
+    }
+    out
+}
+
+struct AlphaStats {
+    opaque: bool,
+    all_transparent: bool,
+}
+
+fn alpha_stats(img: &RgbaImage) -> AlphaStats {
+    let mut opaque = true;
+    let mut all_transparent = true;
+    for p in img.pixels() {
+        let a = p.0[3];
+        opaque &= a == 255;
+        all_transparent &= a == 0;
+    }
+    AlphaStats { opaque, all_transparent }
+}
+
+fn lint_header(info: &BlpInfo, opaque: bool, out: &mut Vec<LintFinding>) {
+    if info.version != BlpVersion::Blp1 {
+        out.push(LintFinding::new(Warning, "not-blp1").with_arg("version", info.version.label()));
+    }
+    if !matches!(info.compression, BlpCompression::Jpeg | BlpCompression::Palette) {
+        out.push(LintFinding::new(Error, "compression").with_arg("compression", info.compression.label()));
+    }
+
+    // Глубина альфы
+    let bits = info.alpha_bits;
+    let supported = match info.version {
+        BlpVersion::Blp2 => matches!(bits, 0 | 1 | 4 | 8),
+        _ => matches!(bits, 0 | 8),
+    };
+    if !supported {
+        out.push(LintFinding::new(Error, "alpha-bits").with_arg("bits", bits));
+    } else if bits > 0 && opaque {
+        out.push(LintFinding::new(Warning, "alpha-unused").with_arg("bits", bits));
+    }
+
+    // Цепочка мипов
+    let present = info
+        .mips
+        .iter()
+        .take_while(|m| m.offset != 0 && m.size != 0)
+        .count();
+    let expected = mip_count(info.width, info.height);
+    if !info.has_mipmaps || present <= 1 {
+        out.push(LintFinding::new(Warning, "no-mips"));
+    } else if present < expected {
+        out.push(
+            LintFinding::new(Error, "mips-truncated")
+                .with_arg("present", present)
+                .with_arg("expected", expected),
+        );
+    }
+    for (i, m) in info.mips.iter().enumerate().take(present) {
+        if m.offset as usize + m.size as usize > info.file_len {
+            out.push(LintFinding::new(Error, "mip-out-of-bounds").with_arg("mip", i));
+        }
+    }
+
+    match info.compression {
+        BlpCompression::Jpeg => lint_jpeg_header(info, present, out),
+        BlpCompression::Palette => lint_palette_alpha(info, present, out),
+        _ => {}
+    }
+}
+
+fn lint_jpeg_header(info: &BlpInfo, present: usize, out: &mut Vec<LintFinding>) {
+    let Some(len) = info.jpeg_header_len else {
+        out.push(LintFinding::new(Error, "jpeg-header-missing"));
+        return;
+    };
+    if len > MAX_JPEG_HEADER {
+        out.push(
+            LintFinding::new(Error, "jpeg-header-large")
+                .with_arg("len", len)
+                .with_arg("max", MAX_JPEG_HEADER),
+        );
+    }
+    // Заголовок лежит сразу после поля длины и не должен налезать на данные мипов
+    let header_end = match info.version {
+        BlpVersion::Blp2 => HEADER_BLP2 + 4 + len as usize,
+        _ => HEADER_BLP1 + 4 + len as usize,
+    };
+    if header_end > info.file_len {
+        out.push(LintFinding::new(Error, "jpeg-header-truncated").with_arg("len", len));
+    } else if let Some(first) = info.mips.iter().take(present).map(|m| m.offset as usize).min()
+        && first < header_end
+    {
+        out.push(LintFinding::new(Error, "jpeg-header-overlap").with_arg("len", len));
+    }
+}
+
+fn lint_palette_alpha(info: &BlpInfo, present: usize, out: &mut Vec<LintFinding>) {
+    for (i, m) in info.mips.iter().enumerate().take(present) {
+        let (w, h) = mip_dims(info.width, info.height, i);
+        let n = w as usize * h as usize;
+        let need = n + (n * info.alpha_bits as usize).div_ceil(8);
+        if (m.size as usize) < need {
+            out.push(
+                LintFinding::new(Error, "palette-alpha-truncated")
+                    .with_arg("mip", i)
+                    .with_arg("size", m.size)
+                    .with_arg("need", need),
+            );
+            break;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::decode::blp_info::BlpMipInfo;
+
+    fn solid(w: u32, h: u32, a: u8) -> Vec<Option<RgbaImage>> {
+        vec![Some(RgbaImage::from_pixel(w, h, blp::image::Rgba([10, 20, 30, a])))]
+    }
+
+    /// Корректный BLP1 JPEG: полная цепочка, мипы вплотную за заголовком.
+    fn jpeg_info(w: u32, h: u32) -> BlpInfo {
+        let header_len = 10;
+        let mut offset = (HEADER_BLP1 + 4 + header_len) as u32;
+        let count = mip_count(w, h);
+        let mips = (0..16)
+            .map(|i| {
+                let (mw, mh) = mip_dims(w, h, i);
+                let size = if i < count { 100 } else { 0 };
+                let m = BlpMipInfo { offset: if i < count { offset } else { 0 }, size, width: mw, height: mh };
+                offset += size;
+                m
+            })
+            .collect();
+        BlpInfo {
+            version: BlpVersion::Blp1,
+            compression: BlpCompression::Jpeg,
+            alpha_bits: 8,
+            alpha_type: None,
+            picture_type: Some(5),
+            has_mipmaps: true,
+            width: w,
+            height: h,
+            mips,
+            jpeg_header_len: Some(header_len as u32),
+            file_len: offset as usize,
+            palette_indices: vec![None; 16],
+        }
+    }
+
+    fn codes(findings: &[LintFinding]) -> Vec<&'static str> {
+        findings.iter().map(|f| f.code).collect()
+    }
+
+    #[test]
+    fn clean_texture_has_no_findings() {
+        let info = jpeg_info(64, 32);
+        assert!(lint_texture(Some(&info), &solid(64, 32, 128)).is_empty());
+        assert!(lint_texture(None, &solid(64, 32, 255)).is_empty());
+    }
+
+    #[test]
+    fn no_image() {
+        let f = lint_texture(None, &[None]);
+        assert_eq!(codes(&f), ["no-image"]);
+        assert_eq!(f[0].severity, Error);
+    }
+
+    #[test]
+    fn size_rules() {
+        let f = lint_texture(None, &solid(100, 60, 255));
+        assert_eq!(codes(&f), ["npot"]);
+        assert_eq!(f[0].args, [("width", "100".to_string()), ("height", "60".to_string())]);
+
+        let f = lint_texture(None, &solid(2048, 16, 255));
+        assert_eq!(codes(&f), ["oversized"]);
+        assert_eq!(f[0].severity, Warning);
+    }
+
+    #[test]
+    fn alpha_rules() {
+        assert_eq!(codes(&lint_texture(None, &solid(8, 8, 0))), ["alpha-all-transparent"]);
+
+        let info = jpeg_info(8, 8);
+        assert_eq!(codes(&lint_texture(Some(&info), &solid(8, 8, 255))), ["alpha-unused"]);
+
+        let mut info = jpeg_info(8, 8);
+        info.alpha_bits = 4;
+        assert_eq!(codes(&lint_texture(Some(&info), &solid(8, 8, 128))), ["alpha-bits"]);
+        // у BLP2 4 бита альфы допустимы
+        info.version = BlpVersion::Blp2;
+        assert_eq!(codes(&lint_texture(Some(&info), &solid(8, 8, 128))), ["not-blp1"]);
+    }
+
+    #[test]
+    fn container_rules() {
+        let mut info = jpeg_info(8, 8);
+        info.compression = BlpCompression::Dxt;
+        assert_eq!(codes(&lint_texture(Some(&info), &solid(8, 8, 128))), ["compression"]);
+    }
+
+    #[test]
+    fn mip_chain_rules() {
+        let mut info = jpeg_info(64, 64);
+        info.mips[3].offset = 0;
+        let f = lint_texture(Some(&info), &solid(64, 64, 128));
+        assert_eq!(codes(&f), ["mips-truncated"]);
+        assert_eq!(f[0].args, [("present", "3".to_string()), ("expected", "7".to_string())]);
+
+        let mut info = jpeg_info(64, 64);
+        info.has_mipmaps = false;
+        assert_eq!(codes(&lint_texture(Some(&info), &solid(64, 64, 128))), ["no-mips"]);
+
+        let mut info = jpeg_info(64, 64);
+        info.file_len -= 1;
+        let f = lint_texture(Some(&info), &solid(64, 64, 128));
+        assert_eq!(codes(&f), ["mip-out-of-bounds"]);
+        assert_eq!(f[0].args, [("mip", "6".to_string())]);
+    }
+
+    #[test]
+    fn jpeg_header_rules() {
+        let mut info = jpeg_info(8, 8);
+        info.jpeg_header_len = None;
+        assert_eq!(codes(&lint_texture(Some(&info), &solid(8, 8, 128))), ["jpeg-header-missing"]);
+
+        let mut info = jpeg_info(8, 8);
+        info.jpeg_header_len = Some(11);
+        assert_eq!(codes(&lint_texture(Some(&info), &solid(8, 8, 128))), ["jpeg-header-overlap"]);
+
+        let mut info = jpeg_info(8, 8);
+        info.jpeg_header_len = Some(MAX_JPEG_HEADER + 1);
+        info.file_len = 10_000;
+        for m in info.mips.iter_mut().filter(|m| m.offset != 0) {
+            m.offset += 2000;
+        }
+        assert_eq!(codes(&lint_texture(Some(&info), &solid(8, 8, 128))), ["jpeg-header-large"]);
+
+        let mut info = jpeg_info(8, 8);
+        info.jpeg_header_len = Some(100_000);
+        let f = lint_texture(Some(&info), &solid(8, 8, 128));
+        assert_eq!(codes(&f), ["jpeg-header-large", "jpeg-header-truncated"]);
+    }
+
+    #[test]
+    fn palette_alpha_rule() {
+        let mut info = jpeg_info(8, 8);
+        info.compression = BlpCompression::Palette;
+        info.jpeg_header_len = None;
+        // 8×8 индексов + 8×8 байт альфы = 128 > 100
+        let f = lint_texture(Some(&info), &solid(8, 8, 128));
+        assert_eq!(codes(&f), ["palette-alpha-truncated"]);
+        assert_eq!(f[0].args[0], ("mip", "0".to_string()));
+    }
+
+    #[test]
+    fn errors_sorted_before_warnings() {
+        let mut info = jpeg_info(100, 60);
+        info.version = BlpVersion::Blp2;
+        let f = lint_texture(Some(&info), &solid(100, 60, 255));
+        let first_warning = f.iter().position(|f| f.severity == Warning).unwrap();
+        assert!(f[..first_warning].iter().all(|f| f.severity == Error));
+        assert!(f[first_warning..].iter().all(|f| f.severity == Warning));
+        assert!(codes(&f).contains(&"npot"));
+        assert!(codes(&f).contains(&"not-blp1"));
+    }
+
+    #[cfg(feature = "ui")]
+    #[test]
+    fn export_mask_rules() {
+        let mut mask = [true; 16];
+        assert!(lint_export_mask(64, 64, &mask).is_empty());
+
+        mask[2] = false;
+        assert_eq!(codes(&lint_export_mask(64, 64, &mask)), ["export-mips-gap", "export-mips-truncated"]);
+
+        let mut mask = [false; 16];
+        mask[..3].fill(true);
+        assert_eq!(codes(&lint_export_mask(64, 64, &mask)), ["export-mips-truncated"]);
+
+        mask[0] = false;
+        assert_eq!(codes(&lint_export_mask(64, 64, &mask)), ["export-no-base"]);
+    }
+}
@@ -0,0 +1,2 @@
+pub mod lint_finding;
+pub mod lint_texture;
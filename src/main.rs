@@ -1,17 +1,39 @@
+#[cfg(feature = "ui")]
 pub mod app;
+mod cli;
+mod decode;
 mod error;
+mod export;
 mod ext;
+mod lint;
+#[cfg(feature = "ui")]
+mod metrics;
+#[cfg(feature = "ui")]
 mod ui;
+#[cfg(feature = "ui")]
 mod paint_bg_maze;
 
+use crate::cli::args::{Cli, EXIT_ERROR};
+use crate::cli::error_chain::ui_error_chain;
+use crate::cli::run_command::run_command;
+use clap::Parser;
+
+#[cfg(feature = "ui")]
 use crate::error::UiError;
+#[cfg(feature = "ui")]
 use app::app::App;
+#[cfg(feature = "ui")]
 use blp::image;
+#[cfg(feature = "ui")]
 use eframe::egui::{IconData, ViewportBuilder, vec2};
+#[cfg(feature = "ui")]
 use eframe::NativeOptions;
+#[cfg(feature = "ui")]
 use std::path::PathBuf;
+#[cfg(feature = "ui")]
 use std::sync::Arc;
 
+#[cfg(feature = "ui")]
 #[inline]
 fn report_error(msg: &str) {
     // stderr (visible if launched from a terminal)
@@ -25,7 +47,8 @@ fn report_error(msg: &str) {
         .show();
 }
 
-fn run_native(path: Option<PathBuf>) -> Result<(), UiError> {
+#[cfg(feature = "ui")]
+fn run_native(paths: Vec<PathBuf>) -> Result<(), UiError> {
     eframe::run_native(
         "blp",
         NativeOptions {
@@ -42,7 +65,7 @@ fn run_native(path: Option<PathBuf>) -> Result<(), UiError> {
         },
         Box::new(move |cc| -> Result<Box<dyn eframe::App>, _> {
             let mut app = App::new(&cc.egui_ctx);
-            if let Err(e) = app.pick_from_file(path.clone()) {
+            if let Err(e) = app.open_paths(paths.clone()) {
                 report_error(&format!("Failed to open file: {}", e));
                 app.error = Some(e);
             }
@@ -56,8 +79,43 @@ fn run_native(path: Option<PathBuf>) -> Result<(), UiError> {
 }
 
 fn main() {
-    if let Err(e) = run_native(None) {
+    let cli = Cli::parse();
+
+    // Команда всегда важнее [PATH]
+    if let Some(cmd) = cli.command {
+        match run_command(cmd) {
+            Ok(code) => std::process::exit(code),
+            Err(e) => {
+                eprintln!("blp: {}", ui_error_chain(&e));
+                std::process::exit(EXIT_ERROR);
+            }
+        }
+    }
+
+    // UI+CLI: [PATH...] открывает вьювер
+    #[cfg(feature = "ui")]
+    if let Err(e) = run_native(cli.paths) {
         eprintln!("UI failed: {:?}", e);
         std::process::exit(1);
     }
+
+    // CLI-only: [PATH...] — проба BLP, без аргументов — справка
+    #[cfg(not(feature = "ui"))]
+    {
+        use crate::cli::args::EXIT_USAGE;
+        use crate::cli::probe::probe;
+        use clap::CommandFactory;
+
+        if cli.paths.is_empty() {
+            let _ = Cli::command().print_help();
+            std::process::exit(EXIT_USAGE);
+        }
+        let code = cli
+            .paths
+            .iter()
+            .map(|p| probe(p))
+            .max()
+            .unwrap_or_default();
+        std::process::exit(code);
+    }
 }
@@ -0,0 +1,192 @@
+use blp::RgbaImage;
+
+/// Среднеквадратичная ошибка по всем четырём каналам. `None` — размеры не совпадают.
+pub fn mse(a: &RgbaImage, b: &RgbaImage) -> Option<f64> {
+    if a.dimensions() != b.dimensions() {
+        return None;
+    }
+    let n = a.as_raw().len();
+    if n == 0 {
+        return Some(0.0);
+    }
+    let sum: u64 = a
+        .as_raw()
+        .iter()
+        .zip(b.as_raw())
+        .map(|(x, y)| {
+            let d = *x as i32 - *y as i32;
+            (d * d) as u64
+        })
+        .sum();
+    Some(sum as f64 / n as f64)
+}
+
+/// PSNR в дБ для 8-битных каналов; идентичные картинки — бесконечность.
+#[inline]
+pub fn psnr(mse: f64) -> f64 {
+    if mse <= 0.0 { f64::INFINITY } else { 10.0 * (255.0 * 255.0 / mse).log10() }
+}
+
+/// Метрики расхождения двух уровней одинакового размера.
+#[derive(Clone, Copy, Debug)]
+pub struct MipMetrics {
+    /// Максимальная разница по любому каналу (0..=255).
+    pub max_error: u8,
+    pub mse: f64,
+    pub psnr: f64,
+    /// SSIM по яркости, окна 8×8 (1.0 — идентичны).
+    pub ssim: f64,
+}
+
+/// Все метрики разом. `None` — размеры не совпадают.
+pub fn mip_metrics(a: &RgbaImage, b: &RgbaImage) -> Option<MipMetrics> {
+    let mse = mse(a, b)?;
+    Some(MipMetrics { max_error: max_error(a, b)?, mse, psnr: psnr(mse), ssim: ssim(a, b)? })
+}
+
+/// Максимальная поканальная разница.
+pub fn max_error(a: &RgbaImage, b: &RgbaImage) -> Option<u8> {
+    if a.dimensions() != b.dimensions() {
+        return None;
+    }
+    Some(
+        a.as_raw()
+            .iter()
+            .zip(b.as_raw())
+            .map(|(x, y)| x.abs_diff(*y))
+            .max()
+            .unwrap_or(0),
+    )
+}
+
+#[inline]
+fn luma(p: &[u8]) -> f64 {
+    0.299 * p[0] as f64 + 0.587 * p[1] as f64 + 0.114 * p[2] as f64
+}
+
+/// Средний SSIM по неперекрывающимся окнам 8×8 (хвостовые окна — сколько влезло).
+pub fn ssim(a: &RgbaImage, b: &RgbaImage) -> Option<f64> {
+    const WIN: u32 = 8;
+    const C1: f64 = (0.01 * 255.0) * (0.01 * 255.0);
+    const C2: f64 = (0.03 * 255.0) * (0.03 * 255.0);
+
+    if a.dimensions() != b.dimensions() {
+        return None;
+    }
+    let (w, h) = a.dimensions();
+    if w == 0 || h == 0 {
+        return Some(1.0);
+    }
+
+    let mut total = 0.0;
+    let mut windows = 0usize;
+    for wy in (0..h).step_by(WIN as usize) {
+        for wx in (0..w).step_by(WIN as usize) {
+            let (mut sa, mut sb, mut saa, mut sbb, mut sab) = (0.0, 0.0, 0.0, 0.0, 0.0);
+            let mut n = 0.0;
+            for y in wy..(wy + WIN).min(h) {
+                for x in wx..(wx + WIN).min(w) {
+                    let la = luma(&a.get_pixel(x, y).0);
+                    let lb = luma(&b.get_pixel(x, y).0);
+                    sa += la;
+                    sb += lb;
+                    saa += la * la;
+                    sbb += lb * lb;
+                    sab += la * lb;
+                    n += 1.0;
+                }
+            }
+            let (ma, mb) = (sa / n, sb / n);
+            let va = saa / n - ma * ma;
+            let vb = sbb / n - mb * mb;
+            let cov = sab / n - ma * mb;
+            total += ((2.0 * ma * mb + C1) * (2.0 * cov + C2)) / ((ma * ma + mb * mb + C1) * (va + vb + C2));
+            windows += 1;
+        }
+    }
+    Some(total / windows as f64)
+}
+
+/// Тепловая карта: максимум поканальной разницы, нормированный на максимум по уровню.
+/// Чёрный — совпадает, дальше фиолетовый → красный → жёлтый → белый.
+pub fn diff_heatmap(a: &RgbaImage, b: &RgbaImage) -> Option<RgbaImage> {
+    const STOPS: [(f32, [f32; 3]); 5] = [(0.0, [0.0, 0.0, 0.0]), (0.3, [90.0, 20.0, 140.0]), (0.6, [230.0, 50.0, 30.0]), (0.85, [255.0, 210.0, 40.0]), (1.0, [255.0, 255.0, 255.0])];
+
+    let max = max_error(a, b)?.max(1) as f32;
+    let (w, h) = a.dimensions();
+    Some(RgbaImage::from_fn(w, h, |x, y| {
+        let (pa, pb) = (a.get_pixel(x, y).0, b.get_pixel(x, y).0);
+        let d = (0..4).map(|c| pa[c].abs_diff(pb[c])).max().unwrap_or(0);
+        let t = d as f32 / max;
+        let i = STOPS
+            .windows(2)
+            .position(|s| t <= s[1].0)
+            .unwrap_or(STOPS.len() - 2);
+        let ((t0, c0), (t1, c1)) = (STOPS[i], STOPS[i + 1]);
+        let k = ((t - t0) / (t1 - t0)).clamp(0.0, 1.0);
+        let c = |j: usize| (c0[j] + (c1[j] - c0[j]) * k).round() as u8;
+        blp::image::Rgba([c(0), c(1), c(2), 255])
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use blp::image::{Rgba, imageops};
+
+    fn gradient(w: u32, h: u32) -> RgbaImage {
+        RgbaImage::from_fn(w, h, |x, y| Rgba([(x * 20) as u8, (y * 25) as u8, 100, 255]))
+    }
+
+    #[test]
+    fn identical_images() {
+        let a = gradient(16, 16);
+        let m = mip_metrics(&a, &a).unwrap();
+        assert_eq!(m.mse, 0.0);
+        assert_eq!(m.max_error, 0);
+        assert_eq!(m.psnr, f64::INFINITY);
+        assert!((m.ssim - 1.0).abs() < 1e-12);
+        assert!(diff_heatmap(&a, &a).unwrap().pixels().all(|p| p.0 == [0, 0, 0, 255]));
+    }
+
+    #[test]
+    fn single_pixel_change() {
+        let a = RgbaImage::from_pixel(4, 4, Rgba([100, 100, 100, 255]));
+        let mut b = a.clone();
+        b.get_pixel_mut(1, 2).0[0] = 110;
+
+        // 10² на один канал из 4×4×4
+        assert_eq!(mse(&a, &b), Some(100.0 / 64.0));
+        assert_eq!(max_error(&a, &b), Some(10));
+        assert!((psnr(100.0 / 64.0) - 10.0 * (255.0f64 * 255.0 * 64.0 / 100.0).log10()).abs() < 1e-9);
+        assert!(ssim(&a, &b).unwrap() < 1.0);
+
+        let heat = diff_heatmap(&a, &b).unwrap();
+        assert_eq!(heat.get_pixel(1, 2).0, [255, 255, 255, 255]);
+        assert_eq!(heat.get_pixel(0, 0).0, [0, 0, 0, 255]);
+    }
+
+    #[test]
+    fn size_mismatch() {
+        let (a, b) = (gradient(4, 4), gradient(4, 2));
+        assert!(mse(&a, &b).is_none());
+        assert!(max_error(&a, &b).is_none());
+        assert!(ssim(&a, &b).is_none());
+        assert!(mip_metrics(&a, &b).is_none());
+        assert!(diff_heatmap(&a, &b).is_none());
+    }
+
+    #[test]
+    fn ssim_partial_windows() {
+        // 10×9: окна 8×8, 2×8, 8×1 и 2×1; правка только в последнем
+        let a = gradient(10, 9);
+        let mut b = a.clone();
+        b.put_pixel(9, 8, Rgba([255, 0, 0, 255]));
+        assert_eq!(ssim(&a, &a), Some(1.0));
+
+        let tail = |img: &RgbaImage| imageops::crop_imm(img, 8, 8, 2, 1).to_image();
+        let tail_ssim = ssim(&tail(&a), &tail(&b)).unwrap();
+        assert!(tail_ssim < 1.0);
+        assert!((ssim(&a, &b).unwrap() - (3.0 + tail_ssim) / 4.0).abs() < 1e-12);
+    }
+}
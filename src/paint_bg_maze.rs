@@ -2,9 +2,11 @@ use eframe::egui::{epaint::Shape, *};
 
 #[allow(dead_code)]
 pub fn paint_bg_maze(ctx: &Context, seed: u64) {
-    let painter = ctx.layer_painter(LayerId::background());
-    let rect = ctx.content_rect();
+    paint_maze(&ctx.layer_painter(LayerId::background()), ctx.content_rect(), seed);
+}
 
+/// Лабиринт в произвольном прямоугольнике (фон под мипами).
+pub fn paint_maze(painter: &Painter, rect: Rect, seed: u64) {
     // геометрия сетки
     let target = 22.0_f32.max(8.0);
     let cols = (rect.width() / target).floor().max(1.0) as i32;
@@ -0,0 +1,140 @@
+use crate::cli::args::mip_mask;
+use crate::decode::any_image_mips::any_image_mips;
+use crate::decode::file_pick_input::FilePickInput;
+use crate::error::UiError;
+use crate::export::export_blp::export_blp;
+use crate::export::export_png::{PngExportMode, export_png};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Во что конвертируем пачку.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BatchTarget {
+    Blp,
+    Png,
+}
+
+impl BatchTarget {
+    #[inline]
+    pub fn ext(self) -> &'static str {
+        match self {
+            BatchTarget::Blp => "blp",
+            BatchTarget::Png => "png",
+        }
+    }
+}
+
+/// Общие настройки для всех файлов пачки.
+#[derive(Clone, Debug)]
+pub struct BatchSettings {
+    pub target: BatchTarget,
+    /// JPEG-качество для BLP.
+    pub quality: u8,
+    /// Сколько уровней писать в BLP (1..=16), как `--mips-limit` в CLI.
+    pub mips_limit: u8,
+    pub png_mode: PngExportMode,
+    /// Папка назначения; `None` — рядом с исходником.
+    pub out_dir: Option<PathBuf>,
+}
+
+/// Этап обработки одного файла.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BatchPhase {
+    Decode,
+    Encode,
+}
+
+#[derive(Clone, Debug)]
+pub enum BatchStatus {
+    Queued,
+    Running(BatchPhase),
+    Done(PathBuf),
+    Failed(UiError),
+    Cancelled,
+}
+
+/// Один файл пачки. `rel` — путь относительно добавленной папки (или просто имя файла).
+#[derive(Clone, Debug)]
+pub struct BatchItem {
+    pub input: PathBuf,
+    pub rel: PathBuf,
+    pub status: BatchStatus,
+}
+
+impl BatchItem {
+    pub fn new(input: PathBuf, rel: PathBuf) -> Self {
+        Self { input, rel, status: BatchStatus::Queued }
+    }
+
+    /// Куда ляжет результат.
+    pub fn output(&self, settings: &BatchSettings) -> PathBuf {
+        match &settings.out_dir {
+            Some(dir) => dir.join(&self.rel),
+            None => self.input.clone(),
+        }
+        .with_extension(settings.target.ext())
+    }
+}
+
+/// Ключ сравнения путей: папка канонизируется, на Windows/macOS регистр не важен.
+pub fn path_key(path: &Path) -> String {
+    let key = match (path.parent().and_then(|p| fs::canonicalize(p).ok()), path.file_name()) {
+        (Some(dir), Some(name)) => dir.join(name),
+        _ => path.to_path_buf(),
+    }
+    .to_string_lossy()
+    .into_owned();
+    if cfg!(any(windows, target_os = "macos")) { key.to_lowercase() } else { key }
+}
+
+/// Конвертирует один файл; `phase` сообщает о переходе к кодированию.
+pub fn convert_file(input: &Path, output: &Path, settings: &BatchSettings, phase: impl Fn(BatchPhase)) -> Result<(), UiError> {
+    let path_arg = || input.to_string_lossy().into_owned();
+    if path_key(output) == path_key(input) {
+        return Err(UiError::new("error-batch-same-file").with_arg("path", path_arg()));
+    }
+
+    phase(BatchPhase::Decode);
+    let img = FilePickInput::Path(input.to_path_buf())
+        .decode()
+        .map_err(|e| UiError::new("error-image-load").with_arg("path", path_arg()).push_blp(e))?;
+    let mips = any_image_mips(&img).map_err(|e| UiError::new("error-image-load").with_arg("path", path_arg()).push_blp(e))?;
+
+    phase(BatchPhase::Encode);
+    if let Some(parent) = output.parent() {
+        fs::create_dir_all(parent).map_err(|e| {
+            UiError::new("error-io-write-file")
+                .with_arg("path", parent.to_string_lossy().into_owned())
+                .with_arg("msg", e.to_string())
+                .push_std(e)
+        })?;
+    }
+    match settings.target {
+        BatchTarget::Blp => export_blp(output, &mips, settings.quality, &mip_mask(None, Some(settings.mips_limit))),
+        BatchTarget::Png => export_png(output, &mips, settings.png_mode, &[true; 16]),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn path_key_resolves_dir() {
+        let dir = std::env::temp_dir();
+        assert_eq!(path_key(&dir.join("a.blp")), path_key(&dir.join(".").join("a.blp")));
+        assert_ne!(path_key(&dir.join("a.blp")), path_key(&dir.join("b.blp")));
+        if cfg!(any(windows, target_os = "macos")) {
+            assert_eq!(path_key(&dir.join("a.BLP")), path_key(&dir.join("a.blp")));
+        }
+    }
+
+    #[test]
+    fn output_replaces_extension() {
+        let item = BatchItem::new(PathBuf::from("in/sub/a.BLP"), PathBuf::from("sub/a.BLP"));
+        let mut settings = BatchSettings { target: BatchTarget::Png, quality: 90, mips_limit: 16, png_mode: PngExportMode::Base, out_dir: None };
+        assert_eq!(item.output(&settings), PathBuf::from("in/sub/a.png"));
+        settings.out_dir = Some(PathBuf::from("out"));
+        assert_eq!(item.output(&settings), PathBuf::from("out/sub/a.png"));
+    }
+}
@@ -0,0 +1,78 @@
+use crate::error::UiError;
+use crate::ui::viewer::layout::batch::batch_job::{BatchPhase, BatchSettings, convert_file};
+use std::collections::VecDeque;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{self, Receiver};
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+/// Задание: индекс строки в окне, вход и выход.
+type Job = (usize, PathBuf, PathBuf);
+
+/// Событие от воркера по строке `usize`.
+pub enum BatchEvent {
+    Phase(usize, BatchPhase),
+    Finished(usize, Result<PathBuf, UiError>),
+}
+
+/// Пул конвертеров пачки: очередь FIFO, отмена — флагом (текущие файлы дорабатываются).
+pub struct BatchPool {
+    queue: Arc<Mutex<VecDeque<Job>>>,
+    cancelled: Arc<AtomicBool>,
+    pub rx: Receiver<BatchEvent>,
+}
+
+impl BatchPool {
+    pub fn start(jobs: Vec<Job>, settings: BatchSettings) -> Self {
+        let queue = Arc::new(Mutex::new(VecDeque::from(jobs)));
+        let cancelled = Arc::new(AtomicBool::new(false));
+        let settings = Arc::new(settings);
+        let (tx, rx) = mpsc::channel();
+
+        let workers = thread::available_parallelism()
+            .map(|n| n.get())
+            .unwrap_or(2)
+            .clamp(1, 8);
+        for _ in 0..workers {
+            let queue = Arc::clone(&queue);
+            let cancelled = Arc::clone(&cancelled);
+            let settings = Arc::clone(&settings);
+            let tx = tx.clone();
+            thread::spawn(move || {
+                loop {
+                    if cancelled.load(Ordering::SeqCst) {
+                        return;
+                    }
+                    let Some((idx, input, output)) = queue.lock().ok().and_then(|mut q| q.pop_front()) else {
+                        return;
+                    };
+                    let res = convert_file(&input, &output, &settings, |p| {
+                        let _ = tx.send(BatchEvent::Phase(idx, p));
+                    })
+                    .map(|_| output);
+                    if tx.send(BatchEvent::Finished(idx, res)).is_err() {
+                        return;
+                    }
+                }
+            });
+        }
+
+        Self { queue, cancelled, rx }
+    }
+
+    /// Не брать новые файлы. Возвращает индексы, которые так и не стартовали.
+    pub fn cancel(&self) -> Vec<usize> {
+        self.cancelled.store(true, Ordering::SeqCst);
+        self.queue
+            .lock()
+            .map(|mut q| q.drain(..).map(|(idx, ..)| idx).collect())
+            .unwrap_or_default()
+    }
+}
+
+impl Drop for BatchPool {
+    fn drop(&mut self) {
+        self.cancel();
+    }
+}
@@ -0,0 +1,53 @@
+use crate::export::export_png::PngExportMode;
+use crate::ui::viewer::layout::batch::batch_job::{BatchItem, BatchSettings, BatchStatus, BatchTarget};
+use crate::ui::viewer::layout::batch::batch_pool::BatchPool;
+
+/// Окно пакетной конвертации.
+pub struct BatchWindow {
+    pub open: bool,
+    pub items: Vec<BatchItem>,
+    pub settings: BatchSettings,
+    /// Есть, пока идёт конвертация.
+    pub pool: Option<BatchPool>,
+}
+
+impl BatchWindow {
+    pub fn new(quality: u8) -> Self {
+        Self {
+            open: false,
+            items: Vec::new(),
+            settings: BatchSettings { target: BatchTarget::Blp, quality: quality.clamp(1, 100), mips_limit: 16, png_mode: PngExportMode::Base, out_dir: None },
+            pool: None,
+        }
+    }
+
+    #[inline]
+    pub fn running(&self) -> bool {
+        self.pool.is_some()
+    }
+
+    /// Сколько файлов уже обработано (успешно, с ошибкой или отменено).
+    pub fn finished(&self) -> usize {
+        self.items
+            .iter()
+            .filter(|i| matches!(i.status, BatchStatus::Done(_) | BatchStatus::Failed(_) | BatchStatus::Cancelled))
+            .count()
+    }
+
+    /// Отчёт в CSV: `input,status,output_or_error`.
+    pub fn report_csv(&self) -> String {
+        let esc = |s: &str| format!("\"{}\"", s.replace('"', "\"\""));
+        let mut out = String::from("input,status,result\n");
+        for item in &self.items {
+            let (status, result) = match &item.status {
+                BatchStatus::Queued => ("queued", String::new()),
+                BatchStatus::Running(_) => ("running", String::new()),
+                BatchStatus::Done(p) => ("ok", p.to_string_lossy().into_owned()),
+                BatchStatus::Failed(e) => ("error", e.to_string()),
+                BatchStatus::Cancelled => ("cancelled", String::new()),
+            };
+            out.push_str(&format!("{},{status},{}\n", esc(&item.input.to_string_lossy()), esc(&result)));
+        }
+        out
+    }
+}
@@ -0,0 +1,299 @@
+use crate::app::app::App;
+use crate::error::UiError;
+use crate::export::export_png::PngExportMode;
+use crate::ui::viewer::layout::batch::batch_job::{BatchItem, BatchPhase, BatchStatus, BatchTarget, path_key};
+use crate::ui::viewer::layout::batch::batch_pool::{BatchEvent, BatchPool};
+use crate::ui::viewer::layout::file_picker::all_image_exts::{all_image_exts, has_image_ext};
+use crate::ui::viewer::layout::file_picker::natural_cmp::natural_cmp;
+use eframe::egui::{Button, Color32, ComboBox, Context, CursorIcon, DragValue, Id, ProgressBar, RichText, ScrollArea, Slider, Window};
+use std::collections::HashSet;
+use std::fs;
+use std::path::PathBuf;
+use std::sync::mpsc::TryRecvError;
+use walkdir::WalkDir;
+
+impl App {
+    /// Добавить файлы и папки (рекурсивно) в очередь пачки.
+    pub(crate) fn batch_add_paths(&mut self, paths: Vec<PathBuf>) {
+        for path in paths {
+            if path.is_dir() {
+                let mut found: Vec<PathBuf> = WalkDir::new(&path)
+                    .follow_links(false)
+                    .into_iter()
+                    .filter_map(Result::ok)
+                    .filter(|e| e.file_type().is_file() && has_image_ext(e.path()))
+                    .map(|e| e.into_path())
+                    .collect();
+                found.sort_by(|a, b| natural_cmp(&a.to_string_lossy(), &b.to_string_lossy()));
+                for file in found {
+                    let rel = file
+                        .strip_prefix(&path)
+                        .map(|r| r.to_path_buf())
+                        .unwrap_or_else(|_| file.clone());
+                    self.batch.items.push(BatchItem::new(file, rel));
+                }
+            } else if let Some(name) = path.file_name().map(PathBuf::from) {
+                self.batch.items.push(BatchItem::new(path, name));
+            }
+        }
+    }
+
+    /// Файлы, чей результат совпал с уже занятым, в очередь не идут — иначе перезапишут друг друга.
+    fn batch_start(&mut self) {
+        let mut taken = HashSet::new();
+        let mut jobs = Vec::new();
+        for (idx, item) in self.batch.items.iter_mut().enumerate() {
+            let output = item.output(&self.batch.settings);
+            if taken.insert(path_key(&output)) {
+                item.status = BatchStatus::Queued;
+                jobs.push((idx, item.input.clone(), output));
+            } else {
+                item.status = BatchStatus::Failed(
+                    UiError::new("error-batch-duplicate-output").with_arg("path", output.to_string_lossy().into_owned()),
+                );
+            }
+        }
+        if !jobs.is_empty() {
+            self.batch.pool = Some(BatchPool::start(jobs, self.batch.settings.clone()));
+        }
+    }
+
+    fn batch_cancel(&mut self) {
+        let Some(pool) = self.batch.pool.as_ref() else {
+            return;
+        };
+        for idx in pool.cancel() {
+            if let Some(item) = self.batch.items.get_mut(idx) {
+                item.status = BatchStatus::Cancelled;
+            }
+        }
+    }
+
+    fn batch_export_report(&mut self) {
+        let Some(path) = rfd::FileDialog::new()
+            .set_file_name("batch-report.csv")
+            .add_filter("CSV", &["csv"])
+            .save_file()
+        else {
+            return;
+        };
+        if let Err(e) = fs::write(&path, self.batch.report_csv()) {
+            self.error = Some(
+                UiError::new("error-io-write-file")
+                    .with_arg("path", path.to_string_lossy().into_owned())
+                    .with_arg("msg", e.to_string())
+                    .push_std(e),
+            );
+        }
+    }
+
+    /// Забирает события воркеров; когда все файлы обработаны — пул закрывается.
+    pub(crate) fn poll_batch(&mut self, ctx: &Context) {
+        let Some(pool) = self.batch.pool.as_ref() else {
+            return;
+        };
+        ctx.request_repaint();
+
+        let disconnected = loop {
+            let (idx, status) = match pool.rx.try_recv() {
+                Ok(BatchEvent::Phase(idx, p)) => (idx, BatchStatus::Running(p)),
+                Ok(BatchEvent::Finished(idx, Ok(out))) => (idx, BatchStatus::Done(out)),
+                Ok(BatchEvent::Finished(idx, Err(e))) => (idx, BatchStatus::Failed(e)),
+                Err(TryRecvError::Empty) => break false,
+                Err(TryRecvError::Disconnected) => break true,
+            };
+            if let Some(item) = self.batch.items.get_mut(idx) {
+                item.status = status;
+            }
+        };
+
+        // Воркеры ушли (паника), не доделав работу — иначе окно ждало бы вечно
+        if disconnected {
+            for item in &mut self.batch.items {
+                if matches!(item.status, BatchStatus::Queued | BatchStatus::Running(_)) {
+                    item.status = BatchStatus::Failed(
+                        UiError::new("error-batch-worker-lost").with_arg("path", item.input.to_string_lossy().into_owned()),
+                    );
+                }
+            }
+        }
+
+        if disconnected || self.batch.finished() == self.batch.items.len() {
+            self.batch.pool = None;
+        }
+    }
+
+    pub(crate) fn draw_batch_window(&mut self, ctx: &Context) {
+        if !self.batch.open {
+            return;
+        }
+
+        let mut open = self.batch.open;
+        let running = self.batch.running();
+        let mut add: Vec<PathBuf> = Vec::new();
+        let (mut start, mut cancel, mut report) = (false, false, false);
+
+        Window::new(self.tr("batch"))
+            .id(Id::new("batch_window"))
+            .open(&mut open)
+            .resizable(true)
+            .default_size([520.0, 420.0])
+            .show(ctx, |ui| {
+                // ------- Файлы -------
+                ui.add_enabled_ui(!running, |ui| {
+                    ui.horizontal(|ui| {
+                        if ui.button(self.tr("batch-add-files")).clicked()
+                            && let Some(files) = rfd::FileDialog::new()
+                                .add_filter(self.tr("filter-all-images"), all_image_exts())
+                                .pick_files()
+                        {
+                            add.extend(files);
+                        }
+                        if ui.button(self.tr("batch-add-folder")).clicked()
+                            && let Some(dir) = rfd::FileDialog::new().pick_folder()
+                        {
+                            add.push(dir);
+                        }
+                        if ui
+                            .add_enabled(!self.batch.items.is_empty(), Button::new(self.tr("batch-clear")))
+                            .clicked()
+                        {
+                            self.batch.items.clear();
+                        }
+                    });
+
+                    ui.separator();
+
+                    // ------- Настройки -------
+                    let png_key = |m: PngExportMode| match m {
+                        PngExportMode::Base => "png-mode-base",
+                        PngExportMode::Mips => "png-mode-mips",
+                        PngExportMode::Strip => "png-mode-strip",
+                    };
+                    let png_modes = [PngExportMode::Base, PngExportMode::Mips, PngExportMode::Strip].map(|m| (m, self.tr(png_key(m))));
+                    let (quality_label, mips_label) = (self.tr("blp-quality"), self.tr("batch-mips-limit"));
+                    let (same_dir_label, choose_dir_label) = (self.tr("batch-same-dir"), self.tr("batch-choose-dir"));
+                    let s = &mut self.batch.settings;
+                    ui.horizontal(|ui| {
+                        ui.selectable_value(&mut s.target, BatchTarget::Blp, "BLP");
+                        ui.selectable_value(&mut s.target, BatchTarget::Png, "PNG");
+                    });
+                    match s.target {
+                        BatchTarget::Blp => {
+                            ui.horizontal(|ui| {
+                                ui.label(quality_label);
+                                ui.add(Slider::new(&mut s.quality, 1..=100));
+                            });
+                            ui.horizontal(|ui| {
+                                ui.label(mips_label);
+                                ui.add(DragValue::new(&mut s.mips_limit).range(1..=16));
+                            });
+                        }
+                        BatchTarget::Png => {
+                            let selected = png_modes
+                                .iter()
+                                .find(|(m, _)| *m == s.png_mode)
+                                .map(|(_, n)| n.clone())
+                                .unwrap_or_default();
+                            ComboBox::from_id_salt("batch_png_mode")
+                                .selected_text(selected)
+                                .show_ui(ui, |ui| {
+                                    for (m, name) in &png_modes {
+                                        ui.selectable_value(&mut s.png_mode, *m, name);
+                                    }
+                                });
+                        }
+                    }
+                    ui.horizontal(|ui| {
+                        let mut same_dir = s.out_dir.is_none();
+                        if ui
+                            .checkbox(&mut same_dir, same_dir_label)
+                            .changed()
+                            && same_dir
+                        {
+                            s.out_dir = None;
+                        }
+                        if ui.button(choose_dir_label).clicked()
+                            && let Some(dir) = rfd::FileDialog::new().pick_folder()
+                        {
+                            s.out_dir = Some(dir);
+                        }
+                        if let Some(dir) = &s.out_dir {
+                            ui.label(dir.to_string_lossy());
+                        }
+                    });
+                });
+
+                ui.separator();
+
+                // ------- Прогресс и действия -------
+                let total = self.batch.items.len();
+                let done = self.batch.finished();
+                let frac = if total == 0 { 0.0 } else { done as f32 / total as f32 };
+                ui.add(ProgressBar::new(frac).text(format!("{done} / {total}")));
+
+                ui.horizontal(|ui| {
+                    if running {
+                        if ui
+                            .button(self.tr("batch-cancel"))
+                            .on_hover_text(self.tr("batch-cancel-hint"))
+                            .clicked()
+                        {
+                            cancel = true;
+                        }
+                    } else if ui
+                        .add_enabled(total > 0, Button::new(self.tr("batch-start")))
+                        .on_hover_cursor(CursorIcon::PointingHand)
+                        .clicked()
+                    {
+                        start = true;
+                    }
+                    if ui
+                        .add_enabled(!running && done > 0, Button::new(self.tr("batch-export-report")))
+                        .clicked()
+                    {
+                        report = true;
+                    }
+                });
+
+                ui.separator();
+
+                // ------- Список файлов -------
+                let row_h = ui.spacing().interact_size.y;
+                ScrollArea::vertical()
+                    .id_salt("batch_items")
+                    .auto_shrink([false, false])
+                    .show_rows(ui, row_h, total, |ui, range| {
+                        for item in &self.batch.items[range] {
+                            let (icon, color, hint) = match &item.status {
+                                BatchStatus::Queued => ("·", Color32::GRAY, self.tr("batch-queued")),
+                                BatchStatus::Running(BatchPhase::Decode) => ("…", Color32::LIGHT_BLUE, self.tr("batch-decoding")),
+                                BatchStatus::Running(BatchPhase::Encode) => ("…", Color32::LIGHT_BLUE, self.tr("batch-encoding")),
+                                BatchStatus::Done(out) => ("✔", Color32::from_rgb(120, 220, 120), out.to_string_lossy().into_owned()),
+                                BatchStatus::Failed(e) => ("✖", Color32::from_rgb(255, 120, 120), e.to_string()),
+                                BatchStatus::Cancelled => ("⊘", Color32::GRAY, self.tr("batch-cancelled")),
+                            };
+                            ui.horizontal(|ui| {
+                                ui.label(RichText::new(icon).color(color).monospace());
+                                ui.label(item.rel.to_string_lossy())
+                                    .on_hover_text(hint);
+                            });
+                        }
+                    });
+            });
+
+        self.batch.open = open;
+        if !add.is_empty() {
+            self.batch_add_paths(add);
+        }
+        if start {
+            self.batch_start();
+        }
+        if cancel {
+            self.batch_cancel();
+        }
+        if report {
+            self.batch_export_report();
+        }
+    }
+}
@@ -0,0 +1,4 @@
+pub mod batch_job;
+pub mod batch_pool;
+pub mod batch_window;
+mod draw_batch_window;
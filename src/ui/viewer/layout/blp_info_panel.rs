@@ -0,0 +1,68 @@
+use crate::app::app::App;
+use crate::ui::viewer::layout::folder::human_size::human_size;
+use eframe::egui::{CollapsingHeader, FontId, Grid, RichText, Ui};
+
+impl App {
+    /// Сворачиваемая панель «Info»: поля заголовка BLP и таблица мипов.
+    pub(crate) fn draw_blp_info(&mut self, ui: &mut Ui) {
+        if self.image.is_none() {
+            return;
+        }
+
+        ui.add_space(ui.spacing().item_spacing.y);
+        ui.separator();
+
+        CollapsingHeader::new(RichText::new(self.tr("info")).strong())
+            .id_salt("blp_info_panel")
+            .default_open(false)
+            .show(ui, |ui| {
+                let Some(info) = self.blp_info.as_ref() else {
+                    ui.label(self.tr("info-not-blp"));
+                    return;
+                };
+
+                let yes_no = |b: bool| self.tr(if b { "yes" } else { "no" });
+                let mut rows: Vec<(String, String)> = vec![
+                    (self.tr("info-version"), info.version.label().to_owned()),
+                    (self.tr("info-compression"), info.compression.label()),
+                    (self.tr("info-alpha-bits"), info.alpha_bits.to_string()),
+                ];
+                if let Some(t) = info.alpha_type {
+                    rows.push((self.tr("info-alpha-type"), t.to_string()));
+                }
+                if let Some(t) = info.picture_type {
+                    rows.push((self.tr("info-picture-type"), t.to_string()));
+                }
+                rows.push((self.tr("info-has-mips"), yes_no(info.has_mipmaps)));
+                rows.push((self.tr("info-size"), format!("{}×{}", info.width, info.height)));
+                if let Some(len) = info.jpeg_header_len {
+                    rows.push((self.tr("info-jpeg-header"), format!("{len} B")));
+                }
+                rows.push((self.tr("info-file-size"), human_size(info.file_len as u64)));
+
+                Grid::new("blp_info_grid")
+                    .num_columns(2)
+                    .striped(true)
+                    .show(ui, |ui| {
+                        for (k, v) in rows {
+                            ui.label(k);
+                            ui.label(RichText::new(v).monospace());
+                            ui.end_row();
+                        }
+                    });
+
+                // Таблица мипов: только записи с данными
+                ui.add_space(ui.spacing().item_spacing.y);
+                ui.label(RichText::new(self.tr("info-mips")).strong());
+                let font = FontId::monospace(11.0);
+                for (i, m) in info.mips.iter().enumerate() {
+                    if m.offset == 0 && m.size == 0 {
+                        continue;
+                    }
+                    let text = format!("#{i:02} {}×{}\n    @{} +{}", m.width, m.height, m.offset, m.size);
+                    ui.label(RichText::new(text).font(font.clone()))
+                        .on_hover_text(format!("offset: 0x{:08X}\nsize: {} B", m.offset, m.size));
+                }
+            });
+    }
+}
@@ -0,0 +1,79 @@
+use crate::metrics::image_diff::MipMetrics;
+use blp::{BlpError, RgbaImage};
+use eframe::egui::TextureHandle;
+use std::path::PathBuf;
+use std::sync::mpsc::Receiver;
+
+/// Как показывать вторую картинку (B) рядом с текущей (A).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CompareMode {
+    /// A и B рядом.
+    SideBySide,
+    /// B поверх A справа от вертикальной шторки.
+    Swipe,
+    /// A и B попеременно каждые полсекунды.
+    Flicker,
+    /// Тепловая карта поканальной разницы.
+    Heatmap,
+}
+
+impl CompareMode {
+    pub const ALL: [CompareMode; 4] = [CompareMode::SideBySide, CompareMode::Swipe, CompareMode::Flicker, CompareMode::Heatmap];
+
+    pub fn key(self) -> &'static str {
+        match self {
+            CompareMode::SideBySide => "compare-side-by-side",
+            CompareMode::Swipe => "compare-swipe",
+            CompareMode::Flicker => "compare-flicker",
+            CompareMode::Heatmap => "compare-heatmap",
+        }
+    }
+}
+
+/// Разница одного уровня: метрики + тепловая карта.
+pub type MipDiff = (MipMetrics, RgbaImage);
+
+/// Вторая картинка для A/B-сравнения и посчитанные по уровням различия.
+pub struct CompareState {
+    pub path: PathBuf,
+    pub mode: CompareMode,
+    /// Положение шторки в режиме Swipe, 0..=1 от левого края мипа.
+    pub swipe: f32,
+    /// Уровни B (len == 16 после загрузки).
+    pub mips: Vec<Option<RgbaImage>>,
+    pub textures: Vec<Option<TextureHandle>>,
+    /// Метрики по уровням; `None` — уровня нет с одной из сторон или размеры не совпали.
+    pub metrics: Vec<Option<MipMetrics>>,
+    pub heatmaps: Vec<Option<TextureHandle>>,
+    pub load_rx: Option<Receiver<Result<Vec<Option<RgbaImage>>, BlpError>>>,
+    pub diff_rx: Option<Receiver<Vec<Option<MipDiff>>>>,
+}
+
+impl CompareState {
+    pub fn new(path: PathBuf, load_rx: Receiver<Result<Vec<Option<RgbaImage>>, BlpError>>) -> Self {
+        Self {
+            path,
+            mode: CompareMode::SideBySide,
+            swipe: 0.5,
+            mips: Vec::new(),
+            textures: vec![None; 16],
+            metrics: vec![None; 16],
+            heatmaps: vec![None; 16],
+            load_rx: Some(load_rx),
+            diff_rx: None,
+        }
+    }
+
+    #[inline]
+    pub fn busy(&self) -> bool {
+        self.load_rx.is_some() || self.diff_rx.is_some()
+    }
+
+    /// Имя файла B для подписи.
+    pub fn name(&self) -> String {
+        self.path
+            .file_name()
+            .map(|s| s.to_string_lossy().into_owned())
+            .unwrap_or_else(|| self.path.to_string_lossy().into_owned())
+    }
+}
@@ -0,0 +1,41 @@
+use crate::app::app::App;
+use eframe::egui::{FontId, RichText, Ui};
+
+impl App {
+    /// Метрики A↔B по уровням (правая панель) + копирование текстом.
+    pub(crate) fn draw_compare_metrics(&mut self, ui: &mut Ui) {
+        let Some(cmp) = self.compare.as_ref() else {
+            return;
+        };
+        if cmp.metrics.iter().all(Option::is_none) {
+            return;
+        }
+
+        ui.separator();
+        ui.label(self.tr("compare-metrics"));
+
+        let lines: Vec<String> = cmp
+            .metrics
+            .iter()
+            .enumerate()
+            .filter_map(|(i, m)| {
+                let m = m.as_ref()?;
+                let psnr = if m.psnr.is_finite() { format!("{:.2}", m.psnr) } else { "∞".to_owned() };
+                Some(format!("#{i:02} max {}  MSE {:.3}\n    PSNR {psnr}  SSIM {:.4}", m.max_error, m.mse, m.ssim))
+            })
+            .collect();
+
+        if ui
+            .button(self.tr("copy"))
+            .on_hover_text(self.tr("compare-copy-hint"))
+            .clicked()
+        {
+            ui.ctx().copy_text(lines.join("\n"));
+        }
+
+        let font = FontId::monospace(11.0);
+        for line in lines {
+            ui.label(RichText::new(line).font(font.clone()));
+        }
+    }
+}
@@ -0,0 +1,51 @@
+use crate::app::app::App;
+use crate::ui::viewer::layout::compare::compare_state::CompareMode;
+use eframe::egui::{CursorIcon, Slider, Spinner, Ui};
+
+impl App {
+    /// Кнопки A/B-сравнения в панели вида: выбор B, режим, шторка, закрыть.
+    pub(crate) fn draw_compare_toolbar(&mut self, ui: &mut Ui) {
+        if ui
+            .button(self.tr("compare"))
+            .on_hover_text(self.tr("compare-hint"))
+            .on_hover_cursor(CursorIcon::PointingHand)
+            .clicked()
+        {
+            self.compare_dialog_open();
+        }
+
+        let labels = CompareMode::ALL.map(|m| (m, self.tr(m.key())));
+        let close_hint = self.tr("compare-close-hint");
+        let swipe_hint = self.tr("compare-swipe-hint");
+        let Some(cmp) = self.compare.as_mut() else {
+            return;
+        };
+
+        ui.label(format!("B: {}", cmp.name()))
+            .on_hover_text(cmp.path.to_string_lossy());
+        if cmp.busy() {
+            ui.add(Spinner::new());
+        }
+        for (mode, label) in labels {
+            if ui
+                .selectable_label(cmp.mode == mode, label)
+                .on_hover_cursor(CursorIcon::PointingHand)
+                .clicked()
+            {
+                cmp.mode = mode;
+            }
+        }
+        if cmp.mode == CompareMode::Swipe {
+            ui.add(Slider::new(&mut cmp.swipe, 0.0..=1.0).show_value(false))
+                .on_hover_text(swipe_hint);
+        }
+        if ui
+            .button("✖")
+            .on_hover_text(close_hint)
+            .on_hover_cursor(CursorIcon::PointingHand)
+            .clicked()
+        {
+            self.close_compare();
+        }
+    }
+}
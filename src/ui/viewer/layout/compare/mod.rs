@@ -0,0 +1,4 @@
+pub mod compare_state;
+mod draw_compare_metrics;
+mod draw_compare_toolbar;
+mod open_compare;
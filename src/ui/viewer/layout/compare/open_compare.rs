@@ -0,0 +1,148 @@
+use crate::app::app::App;
+use crate::decode::any_image_mips::any_image_mips;
+use crate::decode::file_pick_input::FilePickInput;
+use crate::error::UiError;
+use crate::metrics::image_diff::{diff_heatmap, mip_metrics};
+use crate::ui::viewer::layout::compare::compare_state::{CompareMode, CompareState, MipDiff};
+use blp::RgbaImage;
+use eframe::egui::{ColorImage, Context, TextureOptions};
+use std::path::PathBuf;
+use std::sync::mpsc::{self, TryRecvError};
+use std::thread;
+
+fn diff_mips(a: Vec<Option<RgbaImage>>, b: Vec<Option<RgbaImage>>) -> Vec<Option<MipDiff>> {
+    a.iter()
+        .zip(b.iter().chain(std::iter::repeat(&None)))
+        .map(|(a, b)| {
+            let (a, b) = (a.as_ref()?, b.as_ref()?);
+            Some((mip_metrics(a, b)?, diff_heatmap(a, b)?))
+        })
+        .collect()
+}
+
+impl App {
+    /// Выбрать файл B через диалог.
+    pub(crate) fn compare_dialog_open(&mut self) {
+        if let Some(path) = self.image_dialog_pick() {
+            self.open_compare(path);
+        }
+    }
+
+    /// Декодирует B в фоне; метрики посчитаются, когда B будет готова.
+    pub(crate) fn open_compare(&mut self, path: PathBuf) {
+        let (tx, rx) = mpsc::sync_channel(1);
+        self.compare = Some(CompareState::new(path.clone(), rx));
+        thread::spawn(move || {
+            let res = FilePickInput::Path(path)
+                .decode()
+                .and_then(|img| any_image_mips(&img));
+            let _ = tx.send(res);
+        });
+    }
+
+    /// Режим сравнения, если B уже загружена.
+    pub(crate) fn compare_mode(&self) -> Option<CompareMode> {
+        self.compare
+            .as_ref()
+            .filter(|c| !c.mips.is_empty())
+            .map(|c| c.mode)
+    }
+
+    pub(crate) fn close_compare(&mut self) {
+        self.compare = None;
+    }
+
+    /// Пересчитать метрики A↔B (новая A или только что загруженная B).
+    pub(crate) fn refresh_compare_diff(&mut self) {
+        let a = self.mip_images.clone();
+        let Some(cmp) = self.compare.as_mut() else {
+            return;
+        };
+        cmp.metrics.fill(None);
+        cmp.heatmaps.fill_with(|| None);
+        if cmp.mips.is_empty() || !a.first().is_some_and(Option::is_some) {
+            cmp.diff_rx = None;
+            return;
+        }
+
+        let (tx, rx) = mpsc::sync_channel(1);
+        cmp.diff_rx = Some(rx);
+        let b = cmp.mips.clone();
+        thread::spawn(move || {
+            let _ = tx.send(diff_mips(a, b));
+        });
+    }
+
+    /// Текстуры B с текущей фильтрацией и каналами.
+    pub(crate) fn load_compare_textures(&mut self, ctx: &Context) {
+        let Some(cmp) = self.compare.as_ref() else {
+            return;
+        };
+        let options = self.texture_options();
+        let mut textures: Vec<_> = cmp
+            .mips
+            .iter()
+            .enumerate()
+            .map(|(i, mip)| {
+                mip.as_ref()
+                    .map(|rgba| self.mip_texture(ctx, format!("compare_{i}"), rgba, options))
+            })
+            .collect();
+        textures.resize_with(16, || None);
+        if let Some(cmp) = self.compare.as_mut() {
+            cmp.textures = textures;
+        }
+    }
+
+    pub(crate) fn poll_compare(&mut self, ctx: &Context) {
+        let Some(cmp) = self.compare.as_mut() else {
+            return;
+        };
+
+        if let Some(rx) = cmp.load_rx.take() {
+            match rx.try_recv() {
+                Ok(Ok(mips)) => {
+                    cmp.mips = mips;
+                    self.load_compare_textures(ctx);
+                    self.refresh_compare_diff();
+                }
+                Ok(Err(e)) => {
+                    self.error = Some(
+                        UiError::new("error-compare-load")
+                            .with_arg("path", cmp.path.to_string_lossy().into_owned())
+                            .push_blp(e),
+                    );
+                    self.compare = None;
+                }
+                Err(TryRecvError::Empty) => {
+                    cmp.load_rx = Some(rx);
+                    ctx.request_repaint();
+                }
+                Err(TryRecvError::Disconnected) => {
+                    self.compare = None;
+                }
+            }
+            return;
+        }
+
+        if let Some(rx) = cmp.diff_rx.take() {
+            match rx.try_recv() {
+                Ok(diffs) => {
+                    for (i, d) in diffs.into_iter().enumerate().take(16) {
+                        if let Some((m, heat)) = d {
+                            let (w, h) = heat.dimensions();
+                            let ci = ColorImage::from_rgba_unmultiplied([w as usize, h as usize], heat.as_raw());
+                            cmp.metrics[i] = Some(m);
+                            cmp.heatmaps[i] = Some(ctx.load_texture(format!("compare_heat_{i}"), ci, TextureOptions::NEAREST));
+                        }
+                    }
+                }
+                Err(TryRecvError::Empty) => {
+                    cmp.diff_rx = Some(rx);
+                    ctx.request_repaint();
+                }
+                Err(TryRecvError::Disconnected) => {}
+            }
+        }
+    }
+}
@@ -1,56 +1,78 @@
-use eframe::egui::text::{LayoutJob, TextFormat};
-use eframe::egui::{Align2, Area, Color32, Context, FontId, Order, Spinner, vec2};
+use eframe::egui::{Align2, Area, Context, CursorIcon, Key, Order, ProgressBar, RichText, Spinner, vec2};
 
 use crate::app::app::App;
+use crate::decode::decode_progress::DecodeProgress;
+use crate::flargs;
+use crate::ui::viewer::layout::folder::human_size::human_size;
 
 impl App {
-    /// Рисует оверлей со спиннером и анимированными точками "Decoding"
-    pub(crate) fn draw_spinner(&self, ctx: &Context) {
+    /// Рисует оверлей: имя файла, спиннер, общий прогресс с этапом + кнопка отмены (и Esc)
+    pub(crate) fn draw_spinner(&mut self, ctx: &Context) {
+        let mut cancel = ctx.input(|i| i.key_pressed(Key::Escape));
+
+        let name = match &self.picked_file {
+            Some(p) => p
+                .file_name()
+                .map(|n| n.to_string_lossy().into_owned())
+                .unwrap_or_else(|| p.to_string_lossy().into_owned()),
+            None => self.tr("pasted-image"),
+        };
+
+        // Доли общего прогресса: чтение 0–0.4, декод 0.4, мипы 0.6–0.95, заливка 0.95
+        let (frac, stage) = match self.decode_progress {
+            None => (0.0, self.tr("progress-read-start")),
+            Some(DecodeProgress::Read { done, total }) => {
+                let frac = if total == 0 { 0.4 } else { 0.4 * done as f32 / total as f32 };
+                let stage = self.tr_args("progress-read", &flargs!(done = human_size(done), total = human_size(total)));
+                (frac, stage)
+            }
+            Some(DecodeProgress::Decode { blp: false }) => (0.4, self.tr("progress-decode")),
+            Some(DecodeProgress::Decode { blp: true }) => (0.4, self.tr("progress-decode-blp")),
+            Some(DecodeProgress::Mip { n, of }) => {
+                let frac = 0.6 + 0.35 * n as f32 / of.max(1) as f32;
+                (frac, self.tr_args("progress-mip", &flargs!(n = n, of = of)))
+            }
+            Some(DecodeProgress::Upload) => (0.95, self.tr("progress-upload")),
+        };
+
         Area::new("loading_overlay".into())
             .order(Order::Foreground)
             .anchor(Align2::CENTER_CENTER, [0.0, 0.0])
             .show(ctx, |ui| {
-                ui.set_min_size(vec2(220.0, 100.0));
+                ui.set_min_size(vec2(260.0, 120.0));
+                ui.set_max_width(260.0);
                 ui.vertical_centered(|ui| {
                     ui.add(Spinner::new().size(44.0));
                     ui.add_space(8.0);
 
-                    let t = ui.input(|i| i.time);
-                    let phase = ((t * 6.0).floor() as usize) % 6;
-
-                    // Паттерн для трёх точек справа:
-                    // 0: .__   1: .._   2: ...   3: _..   4: __.   5: ___
-                    let pattern: [bool; 3] = match phase {
-                        0 => [true, false, false],
-                        1 => [true, true, false],
-                        2 => [true, true, true],
-                        3 => [false, true, true],
-                        4 => [false, false, true],
-                        _ => [false, false, false],
-                    };
-
-                    let mut job = LayoutJob::default();
-                    let font = FontId::proportional(16.0);
-                    let fg = ui
-                        .visuals()
-                        .widgets
-                        .inactive
-                        .fg_stroke
-                        .color;
-
-                    // слева: всегда три прозрачные точки для выравнивания
-                    job.append("...", 0.0, TextFormat { font_id: font.clone(), color: Color32::TRANSPARENT, ..Default::default() });
-
-                    // текст
-                    job.append("Decoding", 0.0, TextFormat { font_id: font.clone(), color: fg, ..Default::default() });
-
-                    // справа: анимированные три точки
-                    for &visible in &pattern {
-                        job.append(".", 0.0, TextFormat { font_id: font.clone(), color: if visible { fg } else { Color32::TRANSPARENT }, ..Default::default() });
-                    }
+                    ui.label(RichText::new(name).strong())
+                        .on_hover_text(
+                            self.picked_file
+                                .as_ref()
+                                .map(|p| p.to_string_lossy().into_owned())
+                                .unwrap_or_default(),
+                        );
+                    ui.add(
+                        ProgressBar::new(frac)
+                            .desired_width(240.0)
+                            .animate(matches!(self.decode_progress, Some(DecodeProgress::Decode { .. }))),
+                    );
+                    ui.label(stage);
+                    ui.add_space(8.0);
 
-                    ui.label(job);
+                    if ui
+                        .button(self.tr("cancel"))
+                        .on_hover_text(self.tr("cancel-decode-hint"))
+                        .on_hover_cursor(CursorIcon::PointingHand)
+                        .clicked()
+                    {
+                        cancel = true;
+                    }
                 });
             });
+
+        if cancel {
+            self.cancel_decode();
+        }
     }
 }
@@ -1,5 +1,6 @@
 use blp::image::ImageFormat;
 use std::collections::BTreeSet;
+use std::path::Path;
 use std::sync::OnceLock;
 
 static ALL_IMAGE_EXTS: OnceLock<Vec<&'static str>> = OnceLock::new();
@@ -22,3 +23,11 @@ pub(in crate::ui::viewer) fn all_image_exts() -> &'static [&'static str] {
         })
         .as_slice()
 }
+
+/// Расширение файла из списка поддерживаемых (без учёта регистра).
+#[inline]
+pub(in crate::ui::viewer) fn has_image_ext(path: &Path) -> bool {
+    path.extension()
+        .map(|e| e.to_string_lossy().to_ascii_lowercase())
+        .is_some_and(|e| all_image_exts().contains(&e.as_str()))
+}
@@ -0,0 +1,48 @@
+use crate::app::app::App;
+use crate::error::UiError;
+use arboard::{Clipboard, ImageData};
+use std::borrow::Cow;
+
+impl App {
+    /// Кладёт в буфер обмена мип `index` (RGBA источника экспорта, без фильтра каналов).
+    pub(crate) fn copy_mip_to_clipboard(&mut self, index: usize) -> Result<(), UiError> {
+        let img = self
+            .source_mips()
+            .get(index)
+            .and_then(|m| m.as_ref())
+            .ok_or_else(|| UiError::new("error-copy-no-image"))?;
+
+        let (w, h) = img.dimensions();
+        #[allow(unused_mut)]
+        let mut bytes = img.as_raw().clone();
+
+        // Windows: RGBA → BGRA (зеркально вставке)
+        #[cfg(target_os = "windows")]
+        for px in bytes.chunks_exact_mut(4) {
+            px.swap(0, 2);
+        }
+
+        // Буфер держим в App: на Linux содержимое живёт, пока жив владелец
+        if self.clipboard.is_none() {
+            let cb = Clipboard::new().map_err(|e| UiError::new("error-clipboard-init-failed").push_std(e))?;
+            self.clipboard = Some(cb);
+        }
+        let Some(cb) = self.clipboard.as_mut() else {
+            return Ok(());
+        };
+
+        cb.set_image(ImageData {
+            width: w as usize,
+            height: h as usize,
+            bytes: Cow::Owned(bytes),
+        })
+        .map_err(|e| UiError::new("error-clipboard-copy-failed").push_std(e))
+    }
+
+    /// «Copy image» / Ctrl+C: базовый уровень.
+    pub(crate) fn copy_image_to_clipboard(&mut self) {
+        if let Err(e) = self.copy_mip_to_clipboard(0) {
+            self.error = Some(e);
+        }
+    }
+}
@@ -1,5 +1,6 @@
 use crate::ext::path::to_abs_string_with_macros::PathMacrosExt;
 use crate::app::app::App;
+use crate::flargs;
 use crate::ui::viewer::layout::file_picker::hotkey_pressed::hotkey_pressed;
 use crate::ui::i18n::shortcut::platform_cmd_shortcut;
 use crate::ui::i18n::lng_list::LngList;
@@ -8,19 +9,31 @@ use eframe::egui::{self, ComboBox, Context, Key, TextEdit, TopBottomPanel};
 
 impl App {
     pub(crate) fn draw_file_picker(&mut self, ctx: &Context) {
-        for f in ctx.input(|i| i.raw.dropped_files.clone()) {
-            if let Some(path) = f.path {
-                if let Err(e) = self.pick_from_file(Some(path)) {
-                    self.error = Some(e);
-                }
-            }
+        let dropped: Vec<_> = ctx.input(|i| {
+            i.raw
+                .dropped_files
+                .iter()
+                .filter_map(|f| f.path.clone())
+                .collect()
+        });
+        if !dropped.is_empty()
+            && let Err(e) = self.open_paths(dropped)
+        {
+            self.error = Some(e);
         }
 
         let open_hotkey = hotkey_pressed(ctx, Key::O);
         let paste_hotkey = hotkey_pressed(ctx, Key::V);
+        // Ctrl+C не перехватываем у текстовых полей
+        let copy_hotkey = ctx.memory(|m| m.focused().is_none()) && hotkey_pressed(ctx, Key::C);
 
         let mut click_select = false;
+        let mut click_folder = false;
         let mut click_paste = false;
+        let mut click_copy = false;
+        let mut step = 0;
+
+        self.step_file_hotkeys(ctx);
 
         TopBottomPanel::top("file_picker_bar")
             .show_separator_line(true)
@@ -37,6 +50,33 @@ impl App {
                             click_select = true;
                         }
 
+                        // Open folder
+                        if ui
+                            .button(self.tr("open-folder"))
+                            .on_hover_text(self.tr("open-folder-hint"))
+                            .clicked()
+                        {
+                            click_folder = true;
+                        }
+
+                        // Prev / Next в папке файла
+                        ui.add_enabled_ui(self.picked_file.is_some(), |ui| {
+                            if ui
+                                .button("◀")
+                                .on_hover_text(self.tr("prev-file-hint"))
+                                .clicked()
+                            {
+                                step = -1;
+                            }
+                            if ui
+                                .button("▶")
+                                .on_hover_text(self.tr("next-file-hint"))
+                                .clicked()
+                            {
+                                step = 1;
+                            }
+                        });
+
                         // Paste
                         if ui
                             .button(self.tr("paste"))
@@ -46,6 +86,24 @@ impl App {
                         {
                             click_paste = true;
                         }
+
+                        // Copy
+                        if ui
+                            .add_enabled(self.mip_images[0].is_some(), egui::Button::new(self.tr("copy-image")))
+                            .on_hover_text(self.tr_args("copy-image-hint", &flargs!(shortcut = platform_cmd_shortcut("C"))))
+                            .clicked()
+                        {
+                            click_copy = true;
+                        }
+
+                        // Batch
+                        if ui
+                            .button(self.tr("batch-open"))
+                            .on_hover_text(self.tr("batch-open-hint"))
+                            .clicked()
+                        {
+                            self.batch.open = true;
+                        }
                     });
 
                     // Языковой переключатель
@@ -70,7 +128,7 @@ impl App {
                                 .interactive(false),
                         );
                     } else {
-                        let s = self.tr(if self.blp.is_some() { "pasted-image" } else { "drop-hint" });
+                        let s = self.tr(if self.image.is_some() { "pasted-image" } else { "drop-hint" });
                         ui.label(s);
                     }
                 });
@@ -80,12 +138,22 @@ impl App {
         if open_hotkey || click_select {
             self.file_dialog_open();
         }
+        if step != 0 {
+            self.step_file(step);
+        }
+        if click_folder {
+            self.folder_dialog_open();
+        }
         if click_paste || paste_hotkey {
             if let Err(e) = self.pick_from_clipboard() {
                 self.error = Some(e);
             }
         }
 
+        if (click_copy || copy_hotkey) && !self.loading {
+            self.copy_image_to_clipboard();
+        }
+
         // Отображение ошибок через toast
         if let Some(err) = self.error.take() {
             let error_msg = format!("{}", err);
@@ -31,6 +31,15 @@ fn save_last_open_dir(dir: &Path) {
 
 impl App {
     pub(crate) fn file_dialog_open(&mut self) {
+        if let Some(path) = self.image_dialog_pick()
+            && let Err(e) = self.pick_from_file(Some(path))
+        {
+            self.error = Some(e);
+        }
+    }
+
+    /// Диалог выбора одной картинки (запоминает папку), без открытия.
+    pub(crate) fn image_dialog_pick(&self) -> Option<PathBuf> {
         let mut dlg = rfd::FileDialog::new()
             .set_title(self.tr("select-image"));
 
@@ -46,15 +55,27 @@ impl App {
         }
 
         // Пытаемся выбрать файл
-        if let Some(path) = dlg.pick_file() {
-            // Сохраняем директорию для следующего открытия
-            if let Some(parent) = path.parent() {
-                save_last_open_dir(parent);
-            }
-
-            if let Err(e) = self.pick_from_file(Some(path)) {
-                self.error = Some(e);
-            }
+        let path = dlg.pick_file()?;
+        // Сохраняем директорию для следующего открытия
+        if let Some(parent) = path.parent() {
+            save_last_open_dir(parent);
+        }
+        Some(path)
+    }
+
+    pub(crate) fn folder_dialog_open(&mut self) {
+        let mut dlg = rfd::FileDialog::new().set_title(self.tr("select-folder"));
+
+        if let Some(dir) = load_last_open_dir()
+            .or_else(platform_desktop)
+            .or_else(|| std::env::current_dir().ok())
+        {
+            dlg = dlg.set_directory(dir);
+        }
+
+        if let Some(dir) = dlg.pick_folder() {
+            save_last_open_dir(&dir);
+            self.open_folder(dir);
         }
     }
 }
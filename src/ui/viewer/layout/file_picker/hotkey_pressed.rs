@@ -29,3 +29,11 @@ pub(in crate::ui::viewer) fn hotkey_pressed(ctx: &Context, key: Key) -> bool {
         })
     })
 }
+
+/// Клавиша без модификаторов, если фокус не в текстовом поле.
+pub(in crate::ui::viewer) fn nav_key_pressed(ctx: &Context, key: Key) -> bool {
+    if ctx.memory(|m| m.focused().is_some()) {
+        return false;
+    }
+    ctx.input_mut(|i| i.consume_key(Modifiers::NONE, key))
+}
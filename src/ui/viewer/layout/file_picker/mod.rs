@@ -1,9 +1,14 @@
-mod all_image_exts;
+pub mod all_image_exts;
+mod copy_to_clipboard;
 pub mod draw_file_picker;
 mod file_dialog_open;
 mod hotkey_pressed;
 pub mod macos_paste_event;
+pub mod natural_cmp;
 mod pick_from_clipboard;
 mod pick_from_file;
+mod open_paths;
 pub mod poll_decoder;
-pub mod file_pick_input;
+mod spawn_decode;
+mod step_file;
+pub mod upload_mips;
@@ -0,0 +1,71 @@
+use std::cmp::Ordering;
+
+/// «Естественное» сравнение имён: регистронезависимо, числа — по значению
+/// (`icon2.blp` < `icon10.blp`).
+pub(in crate::ui::viewer) fn natural_cmp(a: &str, b: &str) -> Ordering {
+    let mut a = a.chars().peekable();
+    let mut b = b.chars().peekable();
+
+    loop {
+        match (a.peek().copied(), b.peek().copied()) {
+            (None, None) => return Ordering::Equal,
+            (None, Some(_)) => return Ordering::Less,
+            (Some(_), None) => return Ordering::Greater,
+            (Some(ca), Some(cb)) if ca.is_ascii_digit() && cb.is_ascii_digit() => {
+                let na: String = std::iter::from_fn(|| a.next_if(|c| c.is_ascii_digit())).collect();
+                let nb: String = std::iter::from_fn(|| b.next_if(|c| c.is_ascii_digit())).collect();
+                let ta = na.trim_start_matches('0');
+                let tb = nb.trim_start_matches('0');
+                let ord = ta
+                    .len()
+                    .cmp(&tb.len())
+                    .then_with(|| ta.cmp(tb))
+                    .then_with(|| na.len().cmp(&nb.len()));
+                if ord != Ordering::Equal {
+                    return ord;
+                }
+            }
+            (Some(ca), Some(cb)) => {
+                let ord = ca
+                    .to_lowercase()
+                    .cmp(cb.to_lowercase());
+                if ord != Ordering::Equal {
+                    return ord;
+                }
+                a.next();
+                b.next();
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::natural_cmp;
+    use std::cmp::Ordering::{Equal, Greater, Less};
+
+    #[test]
+    fn numbers_by_value() {
+        assert_eq!(natural_cmp("icon2.blp", "icon10.blp"), Less);
+        assert_eq!(natural_cmp("icon10.blp", "icon9.blp"), Greater);
+        assert_eq!(natural_cmp("a1b2", "a1b10"), Less);
+    }
+
+    #[test]
+    fn case_insensitive() {
+        assert_eq!(natural_cmp("Abc.blp", "abd.blp"), Less);
+        assert_eq!(natural_cmp("ABC", "abc"), Equal);
+    }
+
+    #[test]
+    fn leading_zeros_break_ties_only() {
+        assert_eq!(natural_cmp("tex007", "tex7"), Greater);
+        assert_eq!(natural_cmp("tex007", "tex8"), Less);
+    }
+
+    #[test]
+    fn prefix_sorts_first() {
+        assert_eq!(natural_cmp("tex", "tex1"), Less);
+        assert_eq!(natural_cmp("", ""), Equal);
+    }
+}
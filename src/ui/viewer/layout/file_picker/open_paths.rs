@@ -0,0 +1,15 @@
+use crate::app::app::App;
+use crate::error::UiError;
+use std::path::PathBuf;
+
+impl App {
+    /// Открыть несколько файлов разом (argv «Открыть с помощью…», drag&drop).
+    /// Первый сразу декодируется, остальные остаются списком в левой панели.
+    pub(crate) fn open_paths(&mut self, paths: Vec<PathBuf>) -> Result<(), UiError> {
+        let first = paths.first().cloned();
+        if paths.len() > 1 {
+            self.open_list = paths;
+        }
+        self.pick_from_file(first)
+    }
+}
@@ -1,4 +1,4 @@
-use crate::ui::viewer::layout::file_picker::file_pick_input::FilePickInput;
+use crate::decode::file_pick_input::FilePickInput;
 use crate::error::UiError;
 use crate::app::app::App;
 
@@ -19,8 +19,6 @@ impl App {
         use arboard::Clipboard;
         use blp::image::{DynamicImage, ImageFormat};
         use std::io::Cursor;
-        use std::sync::mpsc;
-        use std::thread;
 
         // init буфера обмена
     let mut cb = Clipboard::new().map_err(|e| UiError::new("error-clipboard-init-failed").push_std(e))?;
@@ -58,17 +56,9 @@ impl App {
         // Сброс состояния + запуск декодера
         self.picked_file = None;
         self.error = None;
-        self.blp = None;
-        self.mip_textures.fill_with(|| None);
+        self.clear_decoded();
 
-        let (tx, rx) = mpsc::sync_channel(1);
-        self.decode_rx = Some(rx);
-        self.loading = true;
-
-        thread::spawn(move || {
-            let res = FilePickInput::Bytes(buf).decode();
-            let _ = tx.send(res);
-        });
+        self.spawn_decode(FilePickInput::Bytes(buf));
 
         Ok(())
     }
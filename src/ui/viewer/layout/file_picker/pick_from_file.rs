@@ -1,10 +1,8 @@
-use crate::ui::viewer::layout::file_picker::file_pick_input::FilePickInput;
+use crate::decode::file_pick_input::FilePickInput;
 use crate::error::UiError;
 use crate::ext::path::ensure_readable::EnsureReadable;
 use crate::app::app::App;
 use std::path::PathBuf;
-use std::sync::mpsc;
-use std::thread;
 
 impl App {
     pub(crate) fn pick_from_file(&mut self, path: Option<PathBuf>) -> Result<(), UiError> {
@@ -12,30 +10,26 @@ impl App {
             return Ok(()); // ничего не выбрано
         };
 
-        // Проверяем, что путь доступен для чтения
-        path.as_path().ensure_readable()?;
+        // Открыли что-то не из списка — список больше не актуален
+        if !self.open_list.contains(&path) {
+            self.open_list.clear();
+        }
 
-        // Если это директория, обрабатываем как папку
+        // Если это директория — режим просмотра папки
         if path.is_dir() {
-            // TODO: Implement directory scanning for BLP files
-            return Err(UiError::new("directory-not-supported")
-                .with_arg("path", path.display().to_string()));
+            self.open_folder(path);
+            return Ok(());
         }
 
+        // Проверяем, что путь доступен для чтения
+        path.as_path().ensure_readable()?;
+
         // Обрабатываем как файл
         self.picked_file = Some(path.clone());
-        self.blp = None;
-        self.mip_textures.fill_with(|| None);
-
-        let (tx, rx) = mpsc::sync_channel(1);
-        self.decode_rx = Some(rx);
-        self.loading = true;
+        self.clear_decoded();
 
         // поток для декодирования
-        thread::spawn(move || {
-            let res = FilePickInput::Path(path).decode();
-            let _ = tx.send(res);
-        });
+        self.spawn_decode(FilePickInput::Path(path));
 
         Ok(())
     }
@@ -1,5 +1,8 @@
 use crate::error::UiError;
 use crate::app::app::App;
+use crate::decode::decode_progress::{DecodeEvent, DecodeProgress};
+use crate::decode::file_pick_input::Decoded;
+use crate::lint::lint_texture::lint_texture;
 use eframe::egui::Context;
 use std::sync::mpsc::TryRecvError;
 
@@ -11,6 +14,12 @@ impl App {
         // Чтобы UI не "замерзал", просим перерисовку
         ctx.request_repaint();
 
+        // Прошлый кадр показал «Upload» — теперь реально заливаем
+        if let Some(decoded) = self.decode_ready.take() {
+            self.finish_decode(ctx, decoded);
+            return;
+        }
+
         // ВАЖНО: сначала вынуть rx из self, чтобы дальше можно было менять self.*
         let Some(rx) = self.decode_rx.take() else {
             // канала нет — считать, что загрузка сорвалась
@@ -18,43 +27,66 @@ impl App {
             return;
         };
 
-        match rx.try_recv() {
-            // === успех ===
-            Ok(Ok(any_image)) => {
-                // Заливка текстур только для существующих уровней
-                self.mip_visible.fill(false);
-                // Extract Blp if it's a BLP format
-                use blp::AnyImageData;
-                if let AnyImageData::Blp(ref blp) = any_image.data {
-                    self.blp = Some(blp.clone());
+        // Вычерпываем все события, накопившиеся за кадр
+        loop {
+            match rx.try_recv() {
+                // === событие устаревшего запроса — отбрасываем ===
+                Ok((generation, _)) if generation != self.decode_gen => {}
+
+                Ok((_, DecodeEvent::Progress(p))) => {
+                    self.decode_progress = Some(p);
+                }
+
+                // === успех: заливку откладываем на кадр, чтобы оверлей успел показать этап ===
+                Ok((_, DecodeEvent::Done(Ok(decoded)))) => {
+                    self.decode_progress = Some(DecodeProgress::Upload);
+                    self.decode_ready = Some(decoded);
+                    return; // rx дропаем — декодер завершён
                 }
-                self.image = Some(any_image);
-                self.loading = false;
-                // rx дропаем — декодер завершён
-            }
 
-            // === ошибка из воркера (AppErr) ===
-            Ok(Err(err)) => {
-                    // Вкладываем как причину в "внешний" ключ, если нужен контекст
+                // === ошибка из воркера (AppErr) ===
+                Ok((_, DecodeEvent::Done(Err(err)))) => {
+                    self.keep_mip_visible = None;
+                    // Отменённый запрос сюда не доходит: отмена либо дропает rx, либо меняет поколение
                     self.error = Some(UiError::new("error-poll-decoder").push_blp(err));
-                self.blp = None;
-                self.loading = false;
-                // rx дропаем
-            }
+                    self.blp = None;
+                    self.loading = false;
+                    return; // rx дропаем
+                }
 
-            // === канал пуст — оставляем rx и ждём следующего кадра ===
-            Err(TryRecvError::Empty) => {
-                self.decode_rx = Some(rx); // вернуть канал назад
-                // self.loading остаётся true
-            }
+                // === канал пуст — оставляем rx и ждём следующего кадра ===
+                Err(TryRecvError::Empty) => {
+                    self.decode_rx = Some(rx); // вернуть канал назад
+                    return; // self.loading остаётся true
+                }
 
-            // === воркер умер — фиксируем явную ошибку ===
-            Err(TryRecvError::Disconnected) => {
-                self.error = Some(UiError::new("blp.decode-thread-disconnected").with_arg("msg", "decoder thread disconnected"));
-                self.blp = None;
-                self.loading = false;
-                // rx дропаем
+                // === воркер умер — фиксируем явную ошибку ===
+                Err(TryRecvError::Disconnected) => {
+                    self.keep_mip_visible = None;
+                    self.error = Some(UiError::new("blp.decode-thread-disconnected").with_arg("msg", "decoder thread disconnected"));
+                    self.blp = None;
+                    self.loading = false;
+                    return; // rx дропаем
+                }
             }
         }
     }
+
+    fn finish_decode(&mut self, ctx: &Context, decoded: Decoded) {
+        let any_image = decoded.image;
+        // Заливка текстур только для существующих уровней
+        let keep = self.keep_mip_visible.take();
+        self.upload_mips(ctx, decoded.mips, keep);
+        // Extract Blp if it's a BLP format
+        use blp::AnyImageData;
+        if let AnyImageData::Blp(ref blp) = any_image.data {
+            self.blp = Some(blp.clone());
+        }
+        self.image = Some(any_image);
+        self.blp_info = decoded.blp_info;
+        self.lint = lint_texture(self.blp_info.as_ref(), &self.mip_images);
+        self.refresh_compare_diff();
+        self.decode_progress = None;
+        self.loading = false;
+    }
 }
@@ -0,0 +1,41 @@
+use crate::app::app::App;
+use crate::decode::cancel_token::CancelToken;
+use crate::decode::decode_progress::DecodeEvent;
+use crate::decode::file_pick_input::FilePickInput;
+use std::sync::mpsc;
+use std::thread;
+
+impl App {
+    /// Запускает декодирование в фоне. Предыдущий запрос отменяется,
+    /// а его результат (если всё же долетит) отсекается по номеру поколения.
+    pub(crate) fn spawn_decode(&mut self, input: FilePickInput) {
+        self.decode_cancel.cancel();
+        let cancel = CancelToken::default();
+        self.decode_cancel = cancel.clone();
+        self.decode_gen += 1;
+        let generation = self.decode_gen;
+
+        let (tx, rx) = mpsc::channel();
+        self.decode_rx = Some(rx);
+        self.decode_progress = None;
+        self.decode_ready = None;
+        self.loading = true;
+
+        thread::spawn(move || {
+            let res = input.decode_with_info(&cancel, |p| {
+                let _ = tx.send((generation, DecodeEvent::Progress(p)));
+            });
+            let _ = tx.send((generation, DecodeEvent::Done(res)));
+        });
+    }
+
+    /// Кнопка Cancel / Esc на оверлее: бросаем текущее декодирование.
+    pub(crate) fn cancel_decode(&mut self) {
+        self.decode_cancel.cancel();
+        self.decode_rx = None;
+        self.decode_ready = None;
+        self.decode_progress = None;
+        self.keep_mip_visible = None;
+        self.loading = false;
+    }
+}
@@ -0,0 +1,77 @@
+use crate::app::app::App;
+use crate::ui::viewer::layout::file_picker::all_image_exts::all_image_exts;
+use crate::ui::viewer::layout::file_picker::hotkey_pressed::nav_key_pressed;
+use crate::ui::viewer::layout::file_picker::natural_cmp::natural_cmp;
+use eframe::egui::{Context, Key};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Картинки в той же папке (без подпапок), в естественном порядке.
+fn sibling_images(dir: &Path) -> Vec<PathBuf> {
+    let Ok(rd) = fs::read_dir(dir) else {
+        return Vec::new();
+    };
+    let mut files: Vec<PathBuf> = rd
+        .filter_map(Result::ok)
+        .map(|e| e.path())
+        .filter(|p| p.is_file())
+        .filter(|p| {
+            p.extension()
+                .map(|e| e.to_string_lossy().to_ascii_lowercase())
+                .is_some_and(|e| all_image_exts().contains(&e.as_str()))
+        })
+        .collect();
+    files.sort_by(|a, b| natural_cmp(&a.file_name().unwrap_or_default().to_string_lossy(), &b.file_name().unwrap_or_default().to_string_lossy()));
+    files
+}
+
+impl App {
+    /// Шаг на `delta` файлов по папке текущего файла, с заворотом на краях.
+    /// Видимость мипов сохраняется.
+    pub(crate) fn step_file(&mut self, delta: isize) {
+        let Some(cur) = self.picked_file.clone() else {
+            return;
+        };
+        let Some(dir) = cur.parent() else {
+            return;
+        };
+
+        let files = sibling_images(dir);
+        if files.len() < 2 {
+            return;
+        }
+        let n = files.len() as isize;
+        let idx = files
+            .iter()
+            .position(|p| *p == cur)
+            .map_or(0, |i| i as isize);
+        let next = files[(idx + delta).rem_euclid(n) as usize].clone();
+
+        // Шаг во время загрузки: mip_visible уже сброшен, маску прошлого файла держим из ожидающей
+        if !self.loading || self.keep_mip_visible.is_none() {
+            let levels = self
+                .mip_images
+                .iter()
+                .rposition(Option::is_some)
+                .map_or(0, |i| i + 1);
+            self.keep_mip_visible = Some((self.mip_visible, levels));
+        }
+        if let Err(e) = self.pick_from_file(Some(next)) {
+            // декод не стартовал — маска не нужна (если не ждёт текущий)
+            if !self.loading {
+                self.keep_mip_visible = None;
+            }
+            self.error = Some(e);
+        }
+    }
+
+    /// ←/→ и PageUp/PageDown — предыдущий/следующий файл.
+    pub(crate) fn step_file_hotkeys(&mut self, ctx: &Context) {
+        if nav_key_pressed(ctx, Key::ArrowLeft) || nav_key_pressed(ctx, Key::PageUp) {
+            self.step_file(-1);
+        }
+        if nav_key_pressed(ctx, Key::ArrowRight) || nav_key_pressed(ctx, Key::PageDown) {
+            self.step_file(1);
+        }
+    }
+}
@@ -0,0 +1,76 @@
+use crate::app::app::App;
+use blp::RgbaImage;
+use eframe::egui::{ColorImage, Context, TextureHandle, TextureOptions};
+
+impl App {
+    /// Сбрасывает всё, что относится к предыдущему файлу (текстуры освобождаются при drop).
+    pub(crate) fn clear_decoded(&mut self) {
+        self.image = None;
+        self.blp = None;
+        self.blp_info = None;
+        self.lint.clear();
+        self.pinned.clear();
+        self.quality_preview.reset();
+        self.mip_gen.reset();
+        self.mip_edited = [false; 16];
+        self.mip_images.fill_with(|| None);
+        self.mip_textures.fill_with(|| None);
+    }
+
+    /// Заливает уровни в GPU и включает видимость только для существующих.
+    /// `keep` — маска и число уровней прошлого файла (навигация по папке): видимость берётся из неё,
+    /// а уровни, которых у прошлого файла не было, включаются.
+    pub(crate) fn upload_mips(&mut self, ctx: &Context, mips: Vec<Option<RgbaImage>>, keep: Option<([bool; 16], usize)>) {
+        self.mip_images.fill_with(|| None);
+        self.mip_visible.fill(false);
+        self.mip_edited = [false; 16];
+
+        for (i, mip) in mips.into_iter().take(16).enumerate() {
+            let Some(rgba) = mip else {
+                continue;
+            };
+            let (w, h) = rgba.dimensions();
+            if w == 0 || h == 0 {
+                continue;
+            }
+            self.mip_visible[i] = match keep {
+                Some((mask, levels)) if i < levels => mask[i],
+                _ => true,
+            };
+            self.mip_images[i] = Some(rgba);
+        }
+
+        self.load_mip_textures(ctx);
+    }
+
+    /// (Пере)создаёт текстуры из показываемых уровней с текущей фильтрацией и каналами.
+    pub(crate) fn load_mip_textures(&mut self, ctx: &Context) {
+        let options = self.texture_options();
+
+        let mut textures: Vec<Option<TextureHandle>> = self
+            .display_mips()
+            .iter()
+            .enumerate()
+            .map(|(i, mip)| {
+                mip.as_ref()
+                    .map(|rgba| self.mip_texture(ctx, format!("mip_{i}"), rgba, options))
+            })
+            .collect();
+        textures.resize_with(16, || None);
+        self.mip_textures = textures;
+        self.load_compare_textures(ctx);
+    }
+
+    /// Одна текстура уровня с текущей изоляцией каналов.
+    pub(crate) fn mip_texture(&self, ctx: &Context, name: String, rgba: &RgbaImage, options: TextureOptions) -> TextureHandle {
+        let (w, h) = rgba.dimensions();
+        let px = self.view.channel.apply(rgba.as_raw());
+        let ci = ColorImage::from_rgba_unmultiplied([w as usize, h as usize], &px);
+        ctx.load_texture(name, ci, options)
+    }
+
+    #[inline]
+    pub(crate) fn texture_options(&self) -> TextureOptions {
+        if self.view.nearest { TextureOptions::NEAREST } else { TextureOptions::LINEAR }
+    }
+}
@@ -1,7 +1,8 @@
 use crate::ext::path::to_abs_string_with_macros::PathMacrosExt;
 use crate::app::app::App;
+use crate::export::export_png::png_export_paths;
 use crate::ui::viewer::layout::file_saver::last_safe_dir::{last_save_dir_load, last_save_dir_save};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 /// Предпросмотр того, куда именно полетит файл при сохранении.
 pub enum SavePreview {
@@ -87,6 +88,32 @@ impl App {
         }
     }
 
+    /// Тултип для PNG: те же варианты, но с полным списком файлов для текущего режима.
+    pub(crate) fn save_preview_tooltip_png(&self, preview: &SavePreview) -> String {
+        match preview {
+            SavePreview::Direct(p) => {
+                let paths = png_export_paths(p, self.png_export_mode, self.source_mips(), &self.mip_visible);
+                let mut s = self.tr("save-tooltip-direct");
+                for p in paths {
+                    s.push('\n');
+                    s.push_str(&p.to_abs_string_with_macros());
+                }
+                s
+            }
+            SavePreview::Dialog { start_dir, name } => {
+                let dir = start_dir
+                    .as_ref()
+                    .map(|d| d.to_abs_string_with_macros())
+                    .unwrap_or_else(|| self.tr("save-tooltip-dialog-dir-unknown"));
+                let names: Vec<String> = png_export_paths(Path::new(name), self.png_export_mode, self.source_mips(), &self.mip_visible)
+                    .iter()
+                    .map(|p| p.to_string_lossy().into_owned())
+                    .collect();
+                format!("{}\n{} {}\n{} {}", self.tr("save-tooltip-dialog"), self.tr("save-tooltip-name"), names.join(", "), self.tr("save-tooltip-folder"), dir)
+            }
+        }
+    }
+
     /// Реальное получение пути: учитывает переключатель и запоминает папку.
     pub(crate) fn pick_save_path(&mut self, default_name: &str, ext: &str, desc: String) -> Option<PathBuf> {
         // 1) «рядом», если можем
@@ -118,4 +145,25 @@ impl App {
         }
         Some(ensure_path_has_ext(path, ext))
     }
+
+    /// Папка для многофайлового экспорта: «рядом» с исходником или из диалога.
+    pub(crate) fn pick_save_dir(&self) -> Option<PathBuf> {
+        if self.save_same_dir
+            && let Some(parent) = self.picked_file.as_ref().and_then(|p| p.parent())
+        {
+            return Some(parent.to_path_buf());
+        }
+
+        let mut dlg = rfd::FileDialog::new();
+        if let Some(dir) = last_save_dir_load().or_else(|| {
+            self.picked_file
+                .as_ref()
+                .and_then(|p| p.parent().map(|pp| pp.to_path_buf()))
+        }) {
+            dlg = dlg.set_directory(dir);
+        }
+        let dir = dlg.pick_folder()?;
+        let _ = last_save_dir_save(&dir); // best-effort
+        Some(dir)
+    }
 }
@@ -0,0 +1,38 @@
+use crate::app::app::App;
+use crate::error::UiError;
+use crate::export::icon_set::{IconVariant, export_icon_set, icon_base_name};
+
+impl App {
+    /// Имя иконки без префиксов BTN/DISBTN/… (или `clipboard`).
+    pub(crate) fn icon_set_name(&self) -> String {
+        self.picked_file
+            .as_ref()
+            .and_then(|p| p.file_stem())
+            .map(|s| icon_base_name(&s.to_string_lossy()).to_owned())
+            .unwrap_or_else(|| "clipboard".to_owned())
+    }
+
+    /// Тултип кнопки: какие файлы появятся относительно выбранной папки.
+    pub(crate) fn icon_set_tooltip(&self) -> String {
+        let name = self.icon_set_name();
+        let mut s = self.tr("icon-set-hint");
+        for v in IconVariant::ALL {
+            s.push('\n');
+            s.push_str(&v.rel_path(&name).to_string_lossy());
+        }
+        s
+    }
+
+    /// «Icon set…»: BTN/DISBTN/PAS/ATC из базового уровня в структуре папок WC3.
+    pub(crate) fn export_icon_set_ui(&mut self) {
+        let Some(src) = self.mip_images.first().and_then(|m| m.clone()) else {
+            self.error = Some(UiError::new("error-save-no-image"));
+            return;
+        };
+        let Some(root) = self.pick_save_dir() else {
+            return;
+        };
+        let name = self.icon_set_name();
+        self.error = export_icon_set(&root, &name, &src, self.export_quality).err();
+    }
+}
@@ -1,4 +1,6 @@
 pub mod export_quality;
 mod file_saver;
+mod icon_set_export;
 mod last_safe_dir;
+pub mod png_export_mode;
 pub mod save_same_dir;
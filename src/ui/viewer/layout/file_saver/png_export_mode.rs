@@ -0,0 +1,20 @@
+use crate::export::export_png::PngExportMode;
+use serde::{Deserialize, Serialize};
+
+const APP: &str = env!("CARGO_PKG_NAME");
+const CFG: Option<&str> = Some(stringify!(PngExportMode));
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+struct PngExportModePrefs {
+    pub val: PngExportMode,
+}
+
+pub fn png_export_mode_load() -> PngExportMode {
+    confy::load::<PngExportModePrefs>(APP, CFG)
+        .unwrap_or_default()
+        .val
+}
+
+pub fn png_export_mode_save(val: PngExportMode) -> Result<(), confy::ConfyError> {
+    confy::store(APP, CFG, PngExportModePrefs { val })
+}
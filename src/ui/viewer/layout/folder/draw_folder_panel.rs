@@ -0,0 +1,116 @@
+use crate::app::app::App;
+use crate::ext::path::to_abs_string_with_macros::PathMacrosExt;
+use crate::flargs;
+use crate::ui::viewer::layout::folder::human_size::human_size;
+use eframe::egui::{Align, Button, Context, CursorIcon, Layout, RichText, ScrollArea, SidePanel, Spinner};
+use std::path::PathBuf;
+
+impl App {
+    pub(crate) fn draw_folder_panel(&mut self, ctx: &Context) {
+        let Some(folder) = self.folder.as_ref() else {
+            return;
+        };
+        let root = folder.root.to_abs_string_with_macros();
+        let scanning = folder.scan_rx.is_some();
+        let count = folder.entries.len();
+
+        let gallery = folder.gallery;
+
+        let mut clicked = None;
+        let mut close = false;
+        let mut toggle_gallery = false;
+
+        SidePanel::left("folder_panel")
+            .resizable(true)
+            .default_width(260.0)
+            .show(ctx, |ui| {
+                ui.add_space(ui.spacing().item_spacing.y);
+                ui.horizontal(|ui| {
+                    if scanning {
+                        ui.add(Spinner::new());
+                    }
+                    ui.label(RichText::new(self.tr_args("folder-count", &flargs!(count = count))).strong())
+                        .on_hover_text(root);
+                    ui.with_layout(Layout::right_to_left(Align::Center), |ui| {
+                        if ui
+                            .add(Button::new("✖"))
+                            .on_hover_text(self.tr("folder-close-hint"))
+                            .on_hover_cursor(CursorIcon::PointingHand)
+                            .clicked()
+                        {
+                            close = true;
+                        }
+                        let (icon, hint) = if gallery { ("☰", "folder-show-image-hint") } else { ("▦", "folder-show-gallery-hint") };
+                        if ui
+                            .add(Button::new(icon))
+                            .on_hover_text(self.tr(hint))
+                            .on_hover_cursor(CursorIcon::PointingHand)
+                            .clicked()
+                        {
+                            toggle_gallery = true;
+                        }
+                    });
+                });
+                ui.separator();
+
+                let Some(folder) = self.folder.as_ref() else {
+                    return;
+                };
+                let row_h = ui.spacing().interact_size.y;
+                ScrollArea::vertical()
+                    .id_salt("folder_scroll")
+                    .auto_shrink([false, false])
+                    .show_rows(ui, row_h, folder.entries.len(), |ui, range| {
+                        for entry in &folder.entries[range] {
+                            let sel = self.picked_file.as_ref() == Some(&entry.path);
+                            let dims = entry
+                                .dims
+                                .map(|(w, h)| format!("{w}×{h}"))
+                                .unwrap_or_else(|| "—".to_owned());
+                            let meta = format!("{dims}  {}", human_size(entry.size));
+
+                            ui.horizontal(|ui| {
+                                ui.set_min_height(row_h);
+                                let resp = ui
+                                    .with_layout(Layout::right_to_left(Align::Center), |ui| {
+                                        ui.label(RichText::new(meta).monospace().weak());
+                                        ui.with_layout(Layout::left_to_right(Align::Center), |ui| {
+                                            ui.add(Button::selectable(sel, entry.name.as_str()).truncate())
+                                        })
+                                        .inner
+                                    })
+                                    .inner;
+                                if resp
+                                    .on_hover_text(&entry.name)
+                                    .on_hover_cursor(CursorIcon::PointingHand)
+                                    .clicked()
+                                    && !sel
+                                {
+                                    clicked = Some(entry.path.clone());
+                                }
+                            });
+                        }
+                    });
+            });
+
+        if close {
+            self.folder = None;
+        }
+        if toggle_gallery && let Some(folder) = self.folder.as_mut() {
+            folder.gallery = !folder.gallery;
+        }
+        if let Some(path) = clicked {
+            self.open_from_folder(path);
+        }
+    }
+
+    /// Открыть файл из папки в обычном просмотре мипов.
+    pub(crate) fn open_from_folder(&mut self, path: PathBuf) {
+        if let Some(folder) = self.folder.as_mut() {
+            folder.gallery = false;
+        }
+        if let Err(e) = self.pick_from_file(Some(path)) {
+            self.error = Some(e);
+        }
+    }
+}
@@ -0,0 +1,88 @@
+use crate::app::app::App;
+use crate::ui::viewer::layout::folder::thumb_loader::THUMB_SIZE;
+use eframe::egui::{Align2, CentralPanel, Color32, Context, CursorIcon, FontId, Frame, Rect, ScrollArea, Sense, Stroke, StrokeKind, pos2, vec2};
+use std::path::PathBuf;
+
+impl App {
+    /// Сетка миниатюр папки. Миниатюры запрашиваются только для видимых строк.
+    pub(crate) fn draw_gallery(&mut self, ctx: &Context) {
+        let mut clicked: Option<PathBuf> = None;
+
+        CentralPanel::default()
+            .frame(Frame::default())
+            .show(ctx, |ui| {
+                let Some(folder) = self.folder.as_mut() else {
+                    return;
+                };
+
+                let spacing = ui.spacing().item_spacing;
+                let label_h = ui.text_style_height(&eframe::egui::TextStyle::Small) + spacing.y;
+                let cell = vec2(THUMB_SIZE as f32 + 8.0, THUMB_SIZE as f32 + 8.0 + label_h);
+                let cols = (((ui.available_width() - spacing.x) / (cell.x + spacing.x)).floor() as usize).max(1);
+                let rows = folder.entries.len().div_ceil(cols);
+
+                ScrollArea::vertical()
+                    .id_salt("gallery_scroll")
+                    .auto_shrink([false, false])
+                    .show_rows(ui, cell.y, rows, |ui, range| {
+                        for row in range {
+                            ui.horizontal(|ui| {
+                                ui.add_space(spacing.x);
+                                for entry in folder
+                                    .entries
+                                    .iter()
+                                    .skip(row * cols)
+                                    .take(cols)
+                                {
+                                    let (rect, resp) = ui.allocate_exact_size(cell, Sense::click());
+                                    let sel = self.picked_file.as_ref() == Some(&entry.path);
+                                    let visuals = ui.style().interact_selectable(&resp, sel);
+                                    let painter = ui.painter_at(rect);
+
+                                    painter.rect(rect, 4.0, visuals.weak_bg_fill, Stroke::new(1.0, visuals.bg_stroke.color), StrokeKind::Inside);
+
+                                    let img_rect = Rect::from_min_size(rect.min + vec2(4.0, 4.0), vec2(THUMB_SIZE as f32, THUMB_SIZE as f32));
+                                    match folder.thumbs.get(&entry.path) {
+                                        // ещё не просили — в очередь
+                                        None => {
+                                            folder.thumbs.insert(entry.path.clone(), None);
+                                            folder
+                                                .thumb_loader
+                                                .request(entry.path.clone());
+                                        }
+                                        Some(Some(tex)) => {
+                                            let size = tex.size_vec2();
+                                            let k = (THUMB_SIZE as f32 / size.x.max(size.y)).min(1.0);
+                                            let fit = Rect::from_center_size(img_rect.center(), size * k);
+                                            painter.image(tex.id(), fit, Rect::from_min_max(pos2(0.0, 0.0), pos2(1.0, 1.0)), Color32::WHITE);
+                                        }
+                                        Some(None) => {
+                                            painter.text(img_rect.center(), Align2::CENTER_CENTER, "…", FontId::proportional(18.0), visuals.text_color());
+                                        }
+                                    }
+
+                                    let file_name = entry
+                                        .path
+                                        .file_name()
+                                        .map(|s| s.to_string_lossy().into_owned())
+                                        .unwrap_or_default();
+                                    painter.text(pos2(rect.center().x, rect.bottom() - 4.0), Align2::CENTER_BOTTOM, &file_name, FontId::proportional(11.0), visuals.text_color());
+
+                                    if resp
+                                        .on_hover_text(&entry.name)
+                                        .on_hover_cursor(CursorIcon::PointingHand)
+                                        .clicked()
+                                    {
+                                        clicked = Some(entry.path.clone());
+                                    }
+                                }
+                            });
+                        }
+                    });
+            });
+
+        if let Some(path) = clicked {
+            self.open_from_folder(path);
+        }
+    }
+}
@@ -0,0 +1,41 @@
+use crate::ui::viewer::layout::folder::thumb_loader::ThumbLoader;
+use eframe::egui::TextureHandle;
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::mpsc::Receiver;
+
+/// Одна найденная картинка в папке.
+#[derive(Debug, Clone)]
+pub struct FolderEntry {
+    pub path: PathBuf,
+    /// Путь относительно корня папки (для показа).
+    pub name: String,
+    pub size: u64,
+    /// `None` — заголовок не распознан.
+    pub dims: Option<(u32, u32)>,
+}
+
+/// Режим просмотра папки: корень, найденные файлы и канал фонового сканера.
+pub struct FolderView {
+    pub root: PathBuf,
+    pub entries: Vec<FolderEntry>,
+    pub scan_rx: Option<Receiver<FolderEntry>>, // None — сканирование завершено
+    /// Центральная панель показывает сетку миниатюр вместо мипов.
+    pub gallery: bool,
+    /// Запрошенные миниатюры: нет ключа — ещё не просили, `None` — в работе или не декодировалась.
+    pub thumbs: HashMap<PathBuf, Option<TextureHandle>>,
+    pub thumb_loader: ThumbLoader,
+}
+
+impl FolderView {
+    pub fn new(root: PathBuf, scan_rx: Receiver<FolderEntry>) -> Self {
+        Self {
+            root,
+            entries: Vec::new(),
+            scan_rx: Some(scan_rx),
+            gallery: true,
+            thumbs: HashMap::new(),
+            thumb_loader: ThumbLoader::new(),
+        }
+    }
+}
@@ -0,0 +1,11 @@
+/// 1536 → "1.5 KiB"
+pub(crate) fn human_size(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["B", "KiB", "MiB", "GiB"];
+    let mut val = bytes as f64;
+    let mut unit = 0;
+    while val >= 1024.0 && unit < UNITS.len() - 1 {
+        val /= 1024.0;
+        unit += 1;
+    }
+    if unit == 0 { format!("{bytes} {}", UNITS[0]) } else { format!("{val:.1} {}", UNITS[unit]) }
+}
@@ -0,0 +1,6 @@
+mod draw_gallery;
+pub mod draw_folder_panel;
+pub mod folder_view;
+pub mod human_size;
+mod scan_folder;
+pub mod thumb_loader;
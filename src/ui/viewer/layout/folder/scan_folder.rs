@@ -0,0 +1,97 @@
+use crate::app::app::App;
+use crate::decode::image_dims::image_dims;
+use crate::ui::viewer::layout::file_picker::all_image_exts::has_image_ext;
+use crate::ui::viewer::layout::file_picker::natural_cmp::natural_cmp;
+use crate::ui::viewer::layout::folder::folder_view::{FolderEntry, FolderView};
+use eframe::egui::{ColorImage, Context, TextureOptions};
+use std::path::PathBuf;
+use std::time::Duration;
+use std::sync::mpsc::{self, TryRecvError};
+use std::thread;
+use walkdir::WalkDir;
+
+impl App {
+    /// Переключиться в режим папки и запустить рекурсивное сканирование в фоне.
+    pub(crate) fn open_folder(&mut self, root: PathBuf) {
+        let (tx, rx) = mpsc::channel();
+        self.folder = Some(FolderView::new(root.clone(), rx));
+
+        thread::spawn(move || {
+            for entry in WalkDir::new(&root)
+                .follow_links(false)
+                .into_iter()
+                .filter_map(Result::ok)
+                .filter(|e| e.file_type().is_file() && has_image_ext(e.path()))
+            {
+                let path = entry.path().to_path_buf();
+                let name = path
+                    .strip_prefix(&root)
+                    .unwrap_or(&path)
+                    .to_string_lossy()
+                    .into_owned();
+                let size = entry
+                    .metadata()
+                    .map(|m| m.len())
+                    .unwrap_or(0);
+                let dims = image_dims(&path);
+                // окно закрыли или папку сменили — выходим
+                if tx.send(FolderEntry { path, name, size, dims }).is_err() {
+                    return;
+                }
+            }
+        });
+    }
+
+    /// Забирает найденные файлы из канала сканера.
+    pub(crate) fn poll_folder_scan(&mut self, ctx: &Context) {
+        let Some(folder) = self.folder.as_mut() else {
+            return;
+        };
+        let Some(rx) = folder.scan_rx.as_ref() else {
+            return;
+        };
+        ctx.request_repaint();
+
+        loop {
+            match rx.try_recv() {
+                Ok(entry) => folder.entries.push(entry),
+                Err(TryRecvError::Empty) => break,
+                Err(TryRecvError::Disconnected) => {
+                    folder.scan_rx = None;
+                    folder
+                        .entries
+                        .sort_by(|a, b| natural_cmp(&a.name, &b.name));
+                    break;
+                }
+            }
+        }
+    }
+
+    /// Заливает готовые миниатюры в GPU (не больше пачки за кадр, чтобы не подвешивать UI).
+    pub(crate) fn poll_thumbs(&mut self, ctx: &Context) {
+        const PER_FRAME: usize = 16;
+
+        let Some(folder) = self.folder.as_mut() else {
+            return;
+        };
+        let mut uploaded = 0;
+        while uploaded < PER_FRAME {
+            let Some((path, thumb)) = folder.thumb_loader.try_recv() else {
+                break;
+            };
+            let tex = thumb.map(|rgba| {
+                let (w, h) = rgba.dimensions();
+                let ci = ColorImage::from_rgba_unmultiplied([w as usize, h as usize], rgba.as_raw());
+                ctx.load_texture(format!("thumb_{}", path.display()), ci, TextureOptions::LINEAR)
+            });
+            folder.thumbs.insert(path, tex);
+            uploaded += 1;
+        }
+        if uploaded > 0 {
+            ctx.request_repaint();
+        } else if folder.thumb_loader.pending() > 0 {
+            // воркеры ещё декодируют — проверим канал чуть позже, не дожидаясь движения мыши
+            ctx.request_repaint_after(Duration::from_millis(50));
+        }
+    }
+}
@@ -0,0 +1,128 @@
+use crate::decode::file_pick_input::FilePickInput;
+use blp::image::imageops;
+use blp::{AnyImageData, RgbaImage};
+use std::collections::VecDeque;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::sync::{Arc, Condvar, Mutex};
+use std::thread;
+
+/// Сторона миниатюры в пикселях.
+pub const THUMB_SIZE: u32 = 96;
+
+/// Очередь заданий: стек, чтобы последние запрошенные (то, что сейчас на экране) шли первыми.
+struct Jobs {
+    queue: Mutex<VecDeque<PathBuf>>,
+    cv: Condvar,
+    closed: AtomicBool,
+}
+
+/// Пул фоновых декодеров миниатюр. При drop воркеры завершаются.
+pub struct ThumbLoader {
+    jobs: Arc<Jobs>,
+    rx: Receiver<(PathBuf, Option<RgbaImage>)>,
+    /// Запрошено, но ещё не забрано из канала: воркеры UI не будят, его перерисовкой ждём их.
+    pending: usize,
+}
+
+impl ThumbLoader {
+    pub fn new() -> Self {
+        let jobs = Arc::new(Jobs { queue: Mutex::new(VecDeque::new()), cv: Condvar::new(), closed: AtomicBool::new(false) });
+        let (tx, rx) = mpsc::channel();
+
+        let workers = thread::available_parallelism()
+            .map(|n| n.get())
+            .unwrap_or(2)
+            .clamp(1, 4);
+        for _ in 0..workers {
+            let jobs = Arc::clone(&jobs);
+            let tx: Sender<_> = tx.clone();
+            thread::spawn(move || {
+                while let Some(path) = next_job(&jobs) {
+                    let thumb = decode_thumb(&path);
+                    if tx.send((path, thumb)).is_err() {
+                        return;
+                    }
+                }
+            });
+        }
+
+        Self { jobs, rx, pending: 0 }
+    }
+
+    pub fn request(&mut self, path: PathBuf) {
+        if let Ok(mut q) = self.jobs.queue.lock() {
+            q.push_back(path);
+            self.pending += 1;
+            self.jobs.cv.notify_one();
+        }
+    }
+
+    /// Готовая миниатюра, если есть.
+    pub fn try_recv(&mut self) -> Option<(PathBuf, Option<RgbaImage>)> {
+        let done = self.rx.try_recv().ok()?;
+        self.pending = self.pending.saturating_sub(1);
+        Some(done)
+    }
+
+    #[inline]
+    pub fn pending(&self) -> usize {
+        self.pending
+    }
+}
+
+impl Drop for ThumbLoader {
+    fn drop(&mut self) {
+        self.jobs.closed.store(true, Ordering::SeqCst);
+        self.jobs.cv.notify_all();
+    }
+}
+
+fn next_job(jobs: &Jobs) -> Option<PathBuf> {
+    let mut q = jobs.queue.lock().ok()?;
+    loop {
+        if jobs.closed.load(Ordering::SeqCst) {
+            return None;
+        }
+        if let Some(path) = q.pop_back() {
+            return Some(path);
+        }
+        q = jobs.cv.wait(q).ok()?;
+    }
+}
+
+/// Декодирует файл и ужимает до `THUMB_SIZE`.
+/// У BLP берётся самый маленький мип, который ещё не меньше миниатюры.
+fn decode_thumb(path: &Path) -> Option<RgbaImage> {
+    let img = FilePickInput::Path(path.to_path_buf())
+        .decode()
+        .ok()?;
+
+    let src: RgbaImage = match &img.data {
+        AnyImageData::Blp(blp) => {
+            let mut levels = blp
+                .mipmaps
+                .iter()
+                .filter_map(|m| m.image.as_ref());
+            let base = levels.next()?;
+            levels
+                .rfind(|m| m.width().max(m.height()) >= THUMB_SIZE)
+                .unwrap_or(base)
+                .clone()
+        }
+        _ => img.to_rgba().ok()?,
+    };
+
+    let (w, h) = src.dimensions();
+    if w == 0 || h == 0 {
+        return None;
+    }
+    if w.max(h) <= THUMB_SIZE {
+        return Some(src);
+    }
+    let k = THUMB_SIZE as f32 / w.max(h) as f32;
+    let tw = ((w as f32 * k).round() as u32).max(1);
+    let th = ((h as f32 * k).round() as u32).max(1);
+    Some(imageops::thumbnail(&src, tw, th))
+}
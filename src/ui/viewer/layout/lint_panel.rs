@@ -0,0 +1,69 @@
+use crate::app::app::App;
+use crate::lint::lint_finding::{LintFinding, LintSeverity};
+use crate::lint::lint_texture::lint_export_mask;
+use eframe::egui::{CollapsingHeader, Color32, RichText, Ui};
+use fluent_templates::fluent_bundle::{FluentArgs, FluentValue};
+
+impl App {
+    /// Локализованный текст находки линтера.
+    pub(crate) fn tr_finding(&self, f: &LintFinding) -> String {
+        let mut args = FluentArgs::new();
+        for (k, v) in &f.args {
+            args.set(*k, FluentValue::from(v.clone()));
+        }
+        self.tr_args(&f.locale_key(), &args)
+    }
+
+    /// Сворачиваемый список предупреждений совместимости с WC3:
+    /// находки по открытому файлу + по маске мипов, с которой пойдёт экспорт в BLP.
+    pub(crate) fn draw_lint(&mut self, ui: &mut Ui) {
+        let Some(base) = self.mip_images.first().and_then(|m| m.as_ref()) else {
+            return;
+        };
+        let (w, h) = base.dimensions();
+        let export = lint_export_mask(w, h, &self.mip_visible);
+
+        let errors = self
+            .lint
+            .iter()
+            .chain(&export)
+            .filter(|f| f.severity == LintSeverity::Error)
+            .count();
+        let total = self.lint.len() + export.len();
+
+        ui.add_space(ui.spacing().item_spacing.y);
+        ui.separator();
+
+        let mut title = RichText::new(format!("{} ({total})", self.tr("lint"))).strong();
+        if errors > 0 {
+            title = title.color(Color32::from_rgb(255, 120, 120));
+        }
+        CollapsingHeader::new(title)
+            .id_salt("lint_panel")
+            .default_open(errors > 0)
+            .show(ui, |ui| {
+                if total == 0 {
+                    ui.label(self.tr("lint-ok"));
+                    return;
+                }
+                for f in &self.lint {
+                    self.lint_row(ui, f);
+                }
+                if !export.is_empty() {
+                    ui.label(RichText::new(self.tr("lint-export")).strong());
+                    for f in &export {
+                        self.lint_row(ui, f);
+                    }
+                }
+            });
+    }
+
+    fn lint_row(&self, ui: &mut Ui, f: &LintFinding) {
+        let (icon, color) = match f.severity {
+            LintSeverity::Error => ("⛔", Color32::from_rgb(255, 120, 120)),
+            LintSeverity::Warning => ("⚠", Color32::from_rgb(255, 200, 90)),
+        };
+        ui.label(RichText::new(format!("{icon} {}", self.tr_finding(f))).color(color))
+            .on_hover_text(f.code);
+    }
+}
@@ -0,0 +1,157 @@
+use crate::app::app::App;
+use crate::error::UiError;
+use crate::export::loading_screen::{SCREEN_H, SCREEN_W, TILE, encode_loading_screen, export_loading_screen, reassemble};
+use crate::flargs;
+use crate::ui::viewer::layout::folder::human_size::human_size;
+use crate::ui::viewer::layout::loading_screen::loading_screen_window::LoadingScreenResult;
+use eframe::egui::{Align2, Button, Color32, ColorImage, Context, CursorIcon, FontId, Id, Rect, RichText, Spinner, Stroke, StrokeKind, TextureOptions, Window, vec2};
+use std::sync::mpsc::{self, TryRecvError};
+use std::thread;
+
+impl App {
+    /// Имя набора тайлов: стем исходника или `LoadingScreen`.
+    fn loading_screen_name(&self) -> String {
+        self.picked_file
+            .as_ref()
+            .and_then(|p| p.file_stem())
+            .map(|s| s.to_string_lossy().into_owned())
+            .unwrap_or_else(|| "LoadingScreen".to_owned())
+    }
+
+    /// Забирает готовую нарезку; если сменился файл или качество — режет заново в фоне.
+    pub(crate) fn poll_loading_screen(&mut self, ctx: &Context) {
+        if !self.loading_screen.open || self.loading {
+            return;
+        }
+
+        if let Some(rx) = self.loading_screen.rx.take() {
+            match rx.try_recv() {
+                Ok(Ok(result)) => {
+                    let (w, h) = result.preview.dimensions();
+                    let ci = ColorImage::from_rgba_unmultiplied([w as usize, h as usize], result.preview.as_raw());
+                    self.loading_screen.texture = Some(ctx.load_texture("loading_screen_preview", ci, TextureOptions::LINEAR));
+                    self.loading_screen.result = Some(result);
+                }
+                Ok(Err(e)) => {
+                    self.loading_screen.open = false;
+                    self.error = Some(UiError::new("error-loading-screen").push_blp(e));
+                    return;
+                }
+                Err(TryRecvError::Empty) => {
+                    self.loading_screen.rx = Some(rx);
+                    ctx.request_repaint();
+                    return;
+                }
+                Err(TryRecvError::Disconnected) => {}
+            }
+        }
+
+        let key = (self.decode_gen, self.mip_edit_gen, self.export_quality);
+        if self
+            .loading_screen
+            .result
+            .as_ref()
+            .is_some_and(|r| r.key == key)
+        {
+            return;
+        }
+        let Some(src) = self.mip_images.first().and_then(|m| m.clone()) else {
+            return;
+        };
+
+        let name = self.loading_screen_name();
+        let (tx, rx) = mpsc::channel();
+        self.loading_screen.rx = Some(rx);
+        thread::spawn(move || {
+            let res = encode_loading_screen(&src, &name, key.2).map(|tiles| {
+                let preview = reassemble(&tiles);
+                LoadingScreenResult { key, tiles, preview }
+            });
+            let _ = tx.send(res);
+        });
+        ctx.request_repaint();
+    }
+
+    fn loading_screen_export(&mut self) {
+        if self.loading_screen.result.is_none() {
+            return;
+        }
+        let Some(dir) = self.pick_save_dir() else {
+            return;
+        };
+        let name = self.loading_screen_name();
+        if let Some(result) = self.loading_screen.result.as_ref() {
+            self.error = export_loading_screen(&dir, &name, &result.tiles).err();
+        }
+    }
+
+    /// Окно: собранное из тайлов превью с сеткой, список файлов и экспорт.
+    pub(crate) fn draw_loading_screen_window(&mut self, ctx: &Context) {
+        if !self.loading_screen.open {
+            return;
+        }
+
+        let mut open = self.loading_screen.open;
+        let mut export = false;
+        let busy = self.loading_screen.busy();
+
+        Window::new(self.tr("loading-screen"))
+            .id(Id::new("loading_screen_window"))
+            .open(&mut open)
+            .resizable(true)
+            .default_size([540.0, 560.0])
+            .show(ctx, |ui| {
+                ui.label(self.tr_args("loading-screen-layout", &flargs!(w = SCREEN_W, h = SCREEN_H, tile = TILE)));
+
+                ui.horizontal(|ui| {
+                    ui.label(format!("{}: {}", self.tr("blp-quality"), self.export_quality));
+                    if busy {
+                        ui.add(Spinner::new());
+                    }
+                });
+
+                // ------- Превью: экран из декодированных тайлов + границы тайлов -------
+                if let (Some(tex), Some(result)) = (&self.loading_screen.texture, &self.loading_screen.result) {
+                    let width = ui.available_width().min(SCREEN_W as f32);
+                    let scale = width / SCREEN_W as f32;
+                    let resp = ui.image((tex.id(), vec2(width, SCREEN_H as f32 * scale)));
+                    let painter = ui.painter_at(resp.rect);
+                    let stroke = Stroke::new(1.0, Color32::from_rgba_unmultiplied(255, 255, 0, 160));
+                    for t in &result.tiles {
+                        let min = resp.rect.min + vec2(t.tile.x as f32, t.tile.y as f32) * scale;
+                        let max = min + vec2(t.tile.w as f32, t.tile.h as f32) * scale;
+                        painter.rect_stroke(Rect::from_min_max(min, max), 0.0, stroke, StrokeKind::Inside);
+                        painter.text(min + vec2(4.0, 4.0), Align2::LEFT_TOP, &t.tile.file, FontId::monospace(11.0), Color32::YELLOW);
+                    }
+
+                    ui.add_space(4.0);
+                    for t in &result.tiles {
+                        ui.label(
+                            RichText::new(format!("{}  {}×{} @ {},{}  {}", t.tile.file, t.tile.w, t.tile.h, t.tile.x, t.tile.y, human_size(t.bytes.len() as u64))).monospace(),
+                        );
+                    }
+                } else {
+                    ui.label(self.tr("loading-screen-encoding"));
+                }
+
+                ui.add_space(4.0);
+                let ready = !busy && self.loading_screen.result.is_some();
+                if ui
+                    .add_enabled(ready, Button::new(self.tr("loading-screen-export")))
+                    .on_hover_text(self.tr("loading-screen-export-hint"))
+                    .on_hover_cursor(CursorIcon::PointingHand)
+                    .clicked()
+                {
+                    export = true;
+                }
+            });
+
+        self.loading_screen.open = open;
+        if !open {
+            self.loading_screen.rx = None;
+        }
+        if export {
+            self.loading_screen_export();
+        }
+    }
+}
@@ -0,0 +1,30 @@
+use crate::export::loading_screen::EncodedTile;
+use blp::{BlpError, RgbaImage};
+use eframe::egui::TextureHandle;
+use std::sync::mpsc::Receiver;
+
+/// Для какого файла, правок и качества нарезаны тайлы: `(decode_gen, mip_edit_gen, export_quality)`.
+pub type LoadingScreenKey = (u64, u64, u8);
+
+/// Готовая нарезка: тайлы с байтами BLP и экран, собранный из их декода.
+pub struct LoadingScreenResult {
+    pub key: LoadingScreenKey,
+    pub tiles: Vec<EncodedTile>,
+    pub preview: RgbaImage,
+}
+
+/// Окно экспорта экрана загрузки WC3.
+#[derive(Default)]
+pub struct LoadingScreenWindow {
+    pub open: bool,
+    pub result: Option<LoadingScreenResult>,
+    pub texture: Option<TextureHandle>,
+    pub rx: Option<Receiver<Result<LoadingScreenResult, BlpError>>>,
+}
+
+impl LoadingScreenWindow {
+    #[inline]
+    pub fn busy(&self) -> bool {
+        self.rx.is_some()
+    }
+}
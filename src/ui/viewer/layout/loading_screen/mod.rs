@@ -0,0 +1,2 @@
+mod draw_loading_screen;
+pub mod loading_screen_window;
@@ -0,0 +1,132 @@
+use crate::app::app::App;
+use crate::export::mip_filter::{MipFilter, generate_mip_chain};
+use crate::ui::viewer::layout::mip_gen::mip_gen_state::MipGenResult;
+use blp::RgbaImage;
+use eframe::egui::{ComboBox, Context, CursorIcon, Spinner, Ui};
+use std::sync::mpsc::{self, TryRecvError};
+use std::thread;
+
+fn filter_key(f: MipFilter) -> &'static str {
+    match f {
+        MipFilter::Box => "mip-filter-box",
+        MipFilter::Triangle => "mip-filter-triangle",
+        MipFilter::Lanczos3 => "mip-filter-lanczos3",
+        MipFilter::Kaiser => "mip-filter-kaiser",
+    }
+}
+
+impl App {
+    /// Уровни-источник для показа и экспорта: сгенерированные (если включено и готово), иначе декодированные.
+    pub(crate) fn source_mips(&self) -> &[Option<RgbaImage>] {
+        match &self.mip_gen.result {
+            Some(r) if self.mip_gen.enabled && r.key.0 == self.decode_gen => &r.mips,
+            _ => &self.mip_images,
+        }
+    }
+
+    /// Забирает готовую цепочку; при смене файла или настроек строит новую в фоне.
+    pub(crate) fn poll_mip_gen(&mut self, ctx: &Context) {
+        if !self.mip_gen.enabled || self.loading {
+            return;
+        }
+
+        if let Some(rx) = self.mip_gen.rx.take() {
+            match rx.try_recv() {
+                // Цепочка от старой базы/настроек — не показываем, ниже построится новая
+                Ok(result) if result.key != (self.decode_gen, self.mip_edit_gen, self.mip_gen.settings) => {}
+                Ok(mut result) => {
+                    self.apply_mip_edits(&mut result.mips);
+                    // новые уровни сразу видны в правой панели
+                    for (i, mip) in result.mips.iter().enumerate().take(16) {
+                        if mip.is_some() && self.mip_images[i].is_none() {
+                            self.mip_visible[i] = true;
+                        }
+                    }
+                    self.mip_gen.result = Some(result);
+                    self.quality_preview.reset();
+                    self.load_mip_textures(ctx);
+                }
+                Err(TryRecvError::Empty) => {
+                    self.mip_gen.rx = Some(rx);
+                    ctx.request_repaint();
+                    return;
+                }
+                Err(TryRecvError::Disconnected) => {}
+            }
+        }
+
+        let key = (self.decode_gen, self.mip_edit_gen, self.mip_gen.settings);
+        if self
+            .mip_gen
+            .result
+            .as_ref()
+            .is_some_and(|r| r.key == key)
+        {
+            return;
+        }
+        let Some(base) = self.mip_images.first().and_then(|m| m.clone()) else {
+            return;
+        };
+
+        let (tx, rx) = mpsc::sync_channel(1);
+        self.mip_gen.rx = Some(rx);
+        thread::spawn(move || {
+            let mut mips: Vec<Option<RgbaImage>> = generate_mip_chain(&base, key.2)
+                .into_iter()
+                .map(Some)
+                .collect();
+            mips.resize_with(16, || None);
+            let _ = tx.send(MipGenResult { key, mips });
+        });
+        ctx.request_repaint();
+    }
+
+    /// Настройки генерации мипов (левая панель).
+    pub(crate) fn draw_mip_gen(&mut self, ui: &mut Ui) {
+        let label = self.tr("mip-gen");
+        if ui
+            .checkbox(&mut self.mip_gen.enabled, label)
+            .on_hover_text(self.tr("mip-gen-hint"))
+            .on_hover_cursor(CursorIcon::PointingHand)
+            .changed()
+        {
+            if !self.mip_gen.enabled {
+                self.mip_gen.reset();
+            }
+            self.quality_preview.reset();
+            self.load_mip_textures(ui.ctx());
+        }
+
+        if !self.mip_gen.enabled {
+            return;
+        }
+
+        let filters = MipFilter::ALL.map(|f| (f, self.tr(filter_key(f))));
+        let (gamma_label, gamma_hint) = (self.tr("mip-gen-gamma"), self.tr("mip-gen-gamma-hint"));
+        let (coverage_label, coverage_hint) = (self.tr("mip-gen-coverage"), self.tr("mip-gen-coverage-hint"));
+        let busy = self.mip_gen.busy();
+        let s = &mut self.mip_gen.settings;
+
+        ui.horizontal(|ui| {
+            let selected = filters
+                .iter()
+                .find(|(f, _)| *f == s.filter)
+                .map(|(_, n)| n.clone())
+                .unwrap_or_default();
+            ComboBox::from_id_salt("mip_gen_filter")
+                .selected_text(selected)
+                .show_ui(ui, |ui| {
+                    for (f, name) in &filters {
+                        ui.selectable_value(&mut s.filter, *f, name);
+                    }
+                });
+            if busy {
+                ui.add(Spinner::new());
+            }
+        });
+        ui.checkbox(&mut s.gamma_correct, gamma_label)
+            .on_hover_text(gamma_hint);
+        ui.checkbox(&mut s.preserve_coverage, coverage_label)
+            .on_hover_text(coverage_hint);
+    }
+}
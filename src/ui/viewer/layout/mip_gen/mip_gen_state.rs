@@ -0,0 +1,76 @@
+use crate::export::mip_filter::MipGenSettings;
+use blp::RgbaImage;
+use std::sync::mpsc::Receiver;
+
+/// Для какого файла, правок и настроек построена цепочка: `(decode_gen, mip_edit_gen, settings)`.
+pub type MipGenKey = (u64, u64, MipGenSettings);
+
+pub struct MipGenResult {
+    pub key: MipGenKey,
+    /// Все уровни от базы до 1×1 (len == 16, хвост — `None`).
+    pub mips: Vec<Option<RgbaImage>>,
+}
+
+/// Своя генерация мипов: пока включена, правая панель, превью и экспорт берут уровни отсюда.
+#[derive(Default)]
+pub struct MipGenState {
+    pub enabled: bool,
+    pub settings: MipGenSettings,
+    pub result: Option<MipGenResult>,
+    pub rx: Option<Receiver<MipGenResult>>,
+}
+
+impl MipGenState {
+    /// Забыть результат и незавершённую задачу (новый файл / выключение).
+    pub fn reset(&mut self) {
+        self.result = None;
+        self.rx = None;
+    }
+
+    #[inline]
+    pub fn busy(&self) -> bool {
+        self.rx.is_some()
+    }
+
+    /// Импорт уровня `index`: новая база делает всю цепочку устаревшей — её строим заново,
+    /// прочие уровни просто подменяются в готовой.
+    pub fn replace_level(&mut self, index: usize, img: &RgbaImage) {
+        if index == 0 {
+            self.reset();
+        } else if let Some(r) = self.result.as_mut() {
+            r.mips[index] = Some(img.clone());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::export::mip_filter::generate_mip_chain;
+    use blp::image::Rgba;
+
+    fn state(base: &RgbaImage) -> MipGenState {
+        let settings = MipGenSettings::default();
+        let mut mips: Vec<Option<RgbaImage>> = generate_mip_chain(base, settings).into_iter().map(Some).collect();
+        mips.resize_with(16, || None);
+        MipGenState { enabled: true, settings, result: Some(MipGenResult { key: (1, 0, settings), mips }), rx: None }
+    }
+
+    #[test]
+    fn base_import_drops_chain() {
+        let mut s = state(&RgbaImage::from_pixel(8, 8, Rgba([255, 0, 0, 255])));
+        s.replace_level(0, &RgbaImage::from_pixel(8, 8, Rgba([0, 0, 255, 255])));
+        assert!(s.result.is_none());
+        assert!(!s.busy());
+    }
+
+    #[test]
+    fn lower_import_patches_chain() {
+        let mut s = state(&RgbaImage::from_pixel(8, 8, Rgba([255, 0, 0, 255])));
+        let blue = RgbaImage::from_pixel(2, 2, Rgba([0, 0, 255, 255]));
+        s.replace_level(2, &blue);
+        let mips = &s.result.as_ref().unwrap().mips;
+        assert_eq!(mips[2].as_ref(), Some(&blue));
+        assert_eq!(mips[1].as_ref().unwrap().get_pixel(0, 0).0, [255, 0, 0, 255]);
+    }
+}
@@ -0,0 +1,2 @@
+mod draw_mip_gen;
+pub mod mip_gen_state;
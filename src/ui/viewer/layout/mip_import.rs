@@ -0,0 +1,79 @@
+use crate::app::app::App;
+use crate::decode::file_pick_input::FilePickInput;
+use crate::error::UiError;
+use crate::export::mip_chain::{mip_count, mip_dims};
+use crate::lint::lint_texture::lint_texture;
+use blp::RgbaImage;
+use eframe::egui::Context;
+use std::path::Path;
+
+impl App {
+    /// Импорт уровня `index` из PNG/BLP (и любых других картинок): размер обязан быть `w>>N × h>>N`.
+    pub(crate) fn import_mip(&mut self, ctx: &Context, index: usize) {
+        let Some(path) = self.image_dialog_pick() else {
+            return;
+        };
+        match self.load_mip_file(&path, index) {
+            Ok(img) => self.set_mip(ctx, index, img),
+            Err(e) => self.error = Some(e),
+        }
+    }
+
+    fn load_mip_file(&self, path: &Path, index: usize) -> Result<RgbaImage, UiError> {
+        let (w, h) = self
+            .mip_images
+            .first()
+            .and_then(|m| m.as_ref())
+            .map(|b| b.dimensions())
+            .ok_or_else(|| UiError::new("error-save-no-image"))?;
+        let count = mip_count(w, h);
+        if index >= count {
+            return Err(UiError::new("error-mip-import-index")
+                .with_arg("n", index)
+                .with_arg("count", count));
+        }
+
+        let load_err = |e: blp::BlpError| {
+            UiError::new("error-image-load")
+                .with_arg("path", path.to_string_lossy().into_owned())
+                .push_blp(e)
+        };
+        // У BLP берём базовый уровень файла
+        let img = FilePickInput::Path(path.to_path_buf())
+            .decode()
+            .and_then(|img| img.to_rgba())
+            .map_err(load_err)?;
+
+        let (ew, eh) = mip_dims(w, h, index);
+        let (aw, ah) = img.dimensions();
+        if (aw, ah) != (ew, eh) {
+            return Err(UiError::new("error-mip-import-size")
+                .with_arg("n", index)
+                .with_arg("expected", format!("{ew}×{eh}"))
+                .with_arg("actual", format!("{aw}×{ah}")));
+        }
+        Ok(img)
+    }
+
+    /// Подменяет уровень в редактируемой цепочке; отмечает его, чтобы генерация мипов не затёрла.
+    fn set_mip(&mut self, ctx: &Context, index: usize, img: RgbaImage) {
+        self.mip_gen.replace_level(index, &img);
+        self.mip_images[index] = Some(img);
+        self.mip_edited[index] = true;
+        self.mip_edit_gen += 1;
+        self.mip_visible[index] = true;
+        self.lint = lint_texture(self.blp_info.as_ref(), &self.mip_images);
+        self.refresh_compare_diff();
+        self.quality_preview.reset();
+        self.load_mip_textures(ctx);
+    }
+
+    /// Вернуть вручную импортированные уровни поверх сгенерированной цепочки.
+    pub(crate) fn apply_mip_edits(&self, mips: &mut [Option<RgbaImage>]) {
+        for (i, slot) in mips.iter_mut().enumerate().take(16) {
+            if self.mip_edited[i] {
+                *slot = self.mip_images[i].clone();
+            }
+        }
+    }
+}
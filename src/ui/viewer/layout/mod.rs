@@ -1,7 +1,19 @@
+pub mod batch;
+mod blp_info_panel;
+pub mod compare;
 mod draw_spinner;
 pub mod file_picker;
 pub mod file_saver;
+pub mod folder;
 mod footer;
+mod lint_panel;
+pub mod loading_screen;
+pub mod mip_gen;
+mod mip_import;
+mod open_list;
 pub mod panel_center;
 pub mod panel_left;
 mod panel_right;
+pub mod pot_resize;
+pub mod quality_preview;
+pub mod view;
@@ -0,0 +1,39 @@
+use crate::app::app::App;
+use eframe::egui::{CursorIcon, RichText, Ui};
+
+impl App {
+    /// Список файлов, открытых пачкой; клик — открыть.
+    pub(crate) fn draw_open_list(&mut self, ui: &mut Ui) {
+        if self.open_list.is_empty() {
+            return;
+        }
+
+        ui.add_space(ui.spacing().item_spacing.y);
+        ui.separator();
+        ui.label(RichText::new(self.tr("open-list")).strong());
+
+        let mut clicked = None;
+        for path in &self.open_list {
+            let name = path
+                .file_name()
+                .map(|s| s.to_string_lossy().into_owned())
+                .unwrap_or_else(|| path.to_string_lossy().into_owned());
+            let sel = self.picked_file.as_ref() == Some(path);
+            if ui
+                .selectable_label(sel, name)
+                .on_hover_text(path.to_string_lossy())
+                .on_hover_cursor(CursorIcon::PointingHand)
+                .clicked()
+                && !sel
+            {
+                clicked = Some(path.clone());
+            }
+        }
+
+        if let Some(path) = clicked
+            && let Err(e) = self.pick_from_file(Some(path))
+        {
+            self.error = Some(e);
+        }
+    }
+}
@@ -1,9 +1,12 @@
-use blp::BlpError as BlpLibError;
 use crate::error::UiError;
 use crate::app::app::App;
+use crate::export::export_blp::export_blp;
+use crate::export::export_png::{PngExportMode, export_png};
 use crate::ui::viewer::layout::file_saver::export_quality::export_quality_save;
+use crate::ui::viewer::layout::file_saver::png_export_mode::png_export_mode_save;
 use crate::ui::viewer::layout::file_saver::save_same_dir::save_same_dir_save;
-use eframe::egui::{Button, Context, CursorIcon, Frame, Margin, RichText, ScrollArea, Sense, SidePanel, Slider, vec2};
+use blp::RgbaImage;
+use eframe::egui::{Button, ComboBox, Context, CursorIcon, Frame, Margin, RichText, ScrollArea, Sense, SidePanel, Slider, vec2};
 
 impl App {
     fn default_names(&self) -> (String, String) {
@@ -22,10 +25,11 @@ impl App {
 
     fn run_export<F>(&mut self, f: F)
     where
-    F: FnOnce(&blp::Blp) -> Result<(), BlpLibError>,
+    F: FnOnce(&[Option<RgbaImage>]) -> Result<(), UiError>,
     {
-        let res = if let Some(img) = self.blp.as_ref() { f(img) } else { Err(BlpLibError::new("error-save-no-image")) };
-        self.error = res.err().map(|e| UiError::new("error-save").push_blp(e));
+        let has_base = self.mip_images.first().is_some_and(|m| m.is_some());
+        let res = if has_base { f(self.source_mips()) } else { Err(UiError::new("error-save-no-image")) };
+        self.error = res.err();
     }
 
     pub(crate) fn draw_panel_left(&mut self, ctx: &Context) {
@@ -85,18 +89,24 @@ impl App {
                                     .on_hover_cursor(CursorIcon::PointingHand)
                                     .clicked()
                                 {
-                                    if let Some(_path) = self.pick_save_path(&def_blp, "blp", self.tr("blp-texture")) {
-                                        // TODO: Implement export_blp for new API
-                                        // let export_quality = self.export_quality;
-                                        // let mip_visible = self.mip_visible;
-                                        // self.run_export(|img| img.export_blp(&path, export_quality, &mip_visible));
+                                    if let Some(path) = self.pick_save_path(&def_blp, "blp", self.tr("blp-texture")) {
+                                        let export_quality = self.export_quality;
+                                        let mip_visible = self.mip_visible;
+                                        match self.pot_export_mips() {
+                                            // NPOT → степень двойки: старые мипы не подходят, цепочка строится заново
+                                            Some((mips, mask)) => self.error = export_blp(&path, &mips, export_quality, &mask).err(),
+                                            None => self.run_export(|mips| export_blp(&path, mips, export_quality, &mip_visible)),
+                                        }
                                     }
                                 }
 
+                                // NPOT: предложение привести к степени двойки
+                                self.draw_pot_resize(ui);
+
                                 // Save as PNG…
                                 let (_, def_png) = self.default_names();
                                 let png_preview = self.preview_save_path(&def_png, "png");
-                                let png_tt = self.save_preview_tooltip(&png_preview);
+                                let png_tt = self.save_preview_tooltip_png(&png_preview);
 
                                 if ui
                                     .add_sized([full_width, 0.0], Button::new(self.tr("save-as-png")))
@@ -104,11 +114,59 @@ impl App {
                                     .on_hover_cursor(CursorIcon::PointingHand)
                                     .clicked()
                                 {
-                                    if let Some(_path) = self.pick_save_path(&def_png, "png", self.tr("png-image")) {
-                                        // TODO: Implement export_png for new API
-                                        // self.run_export(|img| img.export_png(&path));
+                                    if let Some(path) = self.pick_save_path(&def_png, "png", self.tr("png-image")) {
+                                        let mode = self.png_export_mode;
+                                        let mip_visible = self.mip_visible;
+                                        self.run_export(|mips| export_png(&path, mips, mode, &mip_visible));
                                     }
                                 }
+
+                                // Режим PNG: база / каждый мип / полоса
+                                let mode_key = |m: PngExportMode| match m {
+                                    PngExportMode::Base => "png-mode-base",
+                                    PngExportMode::Mips => "png-mode-mips",
+                                    PngExportMode::Strip => "png-mode-strip",
+                                };
+                                let ir = ComboBox::from_id_salt("png_export_mode")
+                                    .width(full_width)
+                                    .selected_text(self.tr(mode_key(self.png_export_mode)))
+                                    .show_ui(ui, |ui| {
+                                        for cand in [PngExportMode::Base, PngExportMode::Mips, PngExportMode::Strip] {
+                                            let sel = self.png_export_mode == cand;
+                                            if ui
+                                                .selectable_label(sel, self.tr(mode_key(cand)))
+                                                .on_hover_cursor(CursorIcon::PointingHand)
+                                                .clicked()
+                                                && !sel
+                                            {
+                                                self.png_export_mode = cand;
+                                                let _ = png_export_mode_save(self.png_export_mode);
+                                            }
+                                        }
+                                    });
+                                ir.response
+                                    .on_hover_text(self.tr("png-mode-hint"))
+                                    .on_hover_cursor(CursorIcon::PointingHand);
+
+                                // Icon set… (BTN/DISBTN/PAS/ATC)
+                                if ui
+                                    .add_sized([full_width, 0.0], Button::new(self.tr("icon-set")))
+                                    .on_hover_text(self.icon_set_tooltip())
+                                    .on_hover_cursor(CursorIcon::PointingHand)
+                                    .clicked()
+                                {
+                                    self.export_icon_set_ui();
+                                }
+
+                                // Loading screen… (тайлы 512×512 + манифест)
+                                if ui
+                                    .add_sized([full_width, 0.0], Button::new(self.tr("loading-screen-open")))
+                                    .on_hover_text(self.tr("loading-screen-open-hint"))
+                                    .on_hover_cursor(CursorIcon::PointingHand)
+                                    .clicked()
+                                {
+                                    self.loading_screen.open = true;
+                                }
                             });
 
                             ui.add_space(ui.spacing().item_spacing.y);
@@ -131,6 +189,13 @@ impl App {
                             {
                                 let _ = export_quality_save(self.export_quality);
                             }
+
+                            self.draw_mip_gen(ui);
+                            self.draw_quality_preview(ui);
+
+                            self.draw_open_list(ui);
+                            self.draw_blp_info(ui);
+                            self.draw_lint(ui);
                         });
 
                         let _ = ui.allocate_exact_size(vec2(ui.available_width(), 0.0), Sense::hover());
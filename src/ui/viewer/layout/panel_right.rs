@@ -1,5 +1,5 @@
 use crate::app::app::App;
-use eframe::egui::{Button, Context, Response, RichText, ScrollArea, Sense, SidePanel, Ui, vec2};
+use eframe::egui::{Align, Button, Id, Layout, Color32, Context, Response, RichText, ScrollArea, Sense, SidePanel, Ui, vec2};
 
 impl App {
     pub(crate) fn draw_panel_right(&mut self, ctx: &Context) {
@@ -16,13 +16,57 @@ impl App {
                     .show(ui, |ui| {
                         ui.add_space(spy_f * 2.0);
                             ui.add_enabled_ui(!self.loading, |ui| {
+                                let copy_hint = self.tr("copy-mip-hint");
+                                let (import_label, import_hint, edited_hint) = (self.tr("mip-import"), self.tr("mip-import-hint"), self.tr("mip-edited"));
+                                let has_base = self.mip_images[0].is_some();
+                                let mut copy_mip = None;
+                                let mut import_mip = None;
                                 for i in 0..16 {
                                     let (w, h) = self.mip_textures.get(i)
                                         .and_then(|t| t.as_ref())
                                         .map(|t| (t.size()[0] as u32, t.size()[1] as u32))
                                         .unwrap_or((0, 0));
 
-                                    mipmap_button_row(ui, &mut self.mip_visible[i], i, w, h);
+                                    // Справа — копирование уровня в буфер обмена
+                                    let has_mip = self.source_mips().get(i).is_some_and(Option::is_some);
+                                    // Строка фиксированной высоты: with_layout с Align::Center растянул бы её на всю панель
+                                    let row_size = vec2(ui.available_width(), ui.spacing().interact_size.y);
+                                    ui.allocate_ui_with_layout(row_size, Layout::right_to_left(Align::Center), |ui| {
+                                        if ui
+                                            .add_enabled(has_mip, Button::new("📋"))
+                                            .on_hover_text(&copy_hint)
+                                            .clicked()
+                                        {
+                                            copy_mip = Some(i);
+                                        }
+                                        // ПКМ по строке — импорт уровня; у выключенной кнопки кликов нет, ловим поверх
+                                        let was_on = self.mip_visible[i];
+                                        let mut row = mipmap_button_row(ui, &mut self.mip_visible[i], i, w, h);
+                                        if !was_on {
+                                            row = ui.interact(row.rect, Id::new(("mip_row_menu", i)), Sense::click());
+                                        }
+                                        if self.mip_edited[i] {
+                                            row = row.on_hover_text(&edited_hint);
+                                        }
+                                        row.context_menu(|ui| {
+                                            if ui
+                                                .add_enabled(has_base, Button::new(&import_label))
+                                                .on_hover_text(&import_hint)
+                                                .clicked()
+                                            {
+                                                import_mip = Some(i);
+                                                ui.close();
+                                            }
+                                        });
+                                    });
+                                }
+                                if let Some(i) = import_mip {
+                                    self.import_mip(ui.ctx(), i);
+                                }
+                                if let Some(i) = copy_mip
+                                    && let Err(e) = self.copy_mip_to_clipboard(i)
+                                {
+                                    self.error = Some(e);
                                 }
 
                                 // Кнопки All / None, поровну по ширине
@@ -53,12 +97,58 @@ impl App {
                                 });
                             });
 
+                        self.draw_pinned_samples(ui);
+                        self.draw_compare_metrics(ui);
+
                         let _ = ui.allocate_exact_size(vec2(ui.available_width(), 0.0), Sense::hover());
                     });
             });
     }
 }
 
+impl App {
+    /// Список закреплённых кликом пикселей + копирование текстом.
+    fn draw_pinned_samples(&mut self, ui: &mut Ui) {
+        if self.pinned.is_empty() {
+            return;
+        }
+        ui.separator();
+        ui.label(self.tr("pinned-pixels"));
+        let (copy, copy_hint, clear) = (self.tr("copy"), self.tr("pinned-copy-hint"), self.tr("pinned-clear"));
+        ui.columns(2, |cols| {
+            if cols[0]
+                .button(copy)
+                .on_hover_text(copy_hint)
+                .clicked()
+            {
+                let text: Vec<String> = self.pinned.iter().map(|s| s.to_text()).collect();
+                cols[0].ctx().copy_text(text.join("\n"));
+            }
+            if cols[1]
+                .button(clear)
+                .clicked()
+            {
+                self.pinned.clear();
+            }
+        });
+
+        let mut remove = None;
+        for (i, s) in self.pinned.iter().enumerate() {
+            let [r, g, b, a] = s.rgba;
+            let text = format!("#{:02} {},{} {}", s.mip, s.x, s.y, s.hex());
+            let resp = ui
+                .add(Button::new(RichText::new(text).monospace().color(Color32::from_rgb(r, g, b))).min_size(vec2(ui.available_width(), 0.0)))
+                .on_hover_text(format!("{}\nRGBA({r}, {g}, {b}, {a})\n{}", s.to_text(), self.tr("pinned-remove-hint")));
+            if resp.clicked() {
+                remove = Some(i);
+            }
+        }
+        if let Some(i) = remove {
+            self.pinned.remove(i);
+        }
+    }
+}
+
 pub fn mipmap_button_row(ui: &mut Ui, on: &mut bool, i: usize, w: u32, h: u32) -> Response {
     let row_h = ui.spacing().interact_size.y;
     let width = ui.available_width();
@@ -0,0 +1,198 @@
+use crate::app::app::App;
+use crate::export::mip_filter::generate_mip_chain;
+use crate::export::pot_resize::{POT_SIDES, PotMethod, PotTarget, is_pot, pot_mip_mask};
+use crate::ui::viewer::layout::pot_resize::pot_resize_state::PotPreview;
+use blp::RgbaImage;
+use eframe::egui::{Checkbox, Color32, ColorImage, ComboBox, Context, CursorIcon, Id, RichText, TextureHandle, Ui, Window, vec2};
+
+fn method_key(m: PotMethod) -> &'static str {
+    match m {
+        PotMethod::Lanczos3 => "pot-method-lanczos3",
+        PotMethod::Bilinear => "pot-method-bilinear",
+        PotMethod::Nearest => "pot-method-nearest",
+        PotMethod::Pad => "pot-method-pad",
+    }
+}
+
+impl App {
+    /// Размер базового уровня, если он не степень двойки.
+    fn npot_base_dims(&self) -> Option<(u32, u32)> {
+        let (w, h) = self.mip_images.first()?.as_ref()?.dimensions();
+        (!is_pot(w) || !is_pot(h)).then_some((w, h))
+    }
+
+    /// Вручную импортированные уровни новая цепочка потеряла бы — пока они есть, приведение выключено.
+    fn has_mip_edits(&self) -> bool {
+        self.mip_edited.iter().any(|&e| e)
+    }
+
+    /// Уровни и маска для экспорта в BLP после приведения базы к степени двойки; `None` — писать как есть.
+    /// Цепочка строится заново: своей генерацией мипов, если она включена, иначе — в `export_blp`.
+    pub(crate) fn pot_export_mips(&self) -> Option<(Vec<Option<RgbaImage>>, [bool; 16])> {
+        if !self.pot.enabled || self.has_mip_edits() {
+            return None;
+        }
+        self.npot_base_dims()?;
+        let base = self.pot.settings.apply(self.mip_images.first()?.as_ref()?)?;
+        let existed: [bool; 16] = std::array::from_fn(|i| self.source_mips().get(i).is_some_and(Option::is_some));
+        let (w, h) = base.dimensions();
+        let mask = pot_mip_mask(w, h, &self.mip_visible, &existed);
+        let mips = if self.mip_gen.enabled {
+            generate_mip_chain(&base, self.mip_gen.settings)
+                .into_iter()
+                .map(Some)
+                .collect()
+        } else {
+            vec![Some(base)]
+        };
+        Some((mips, mask))
+    }
+
+    /// Блок под «Save as BLP»: предложение привести NPOT-исходник к степени двойки.
+    pub(crate) fn draw_pot_resize(&mut self, ui: &mut Ui) {
+        let Some((w, h)) = self.npot_base_dims() else {
+            return;
+        };
+
+        ui.label(RichText::new(self.tr("pot-npot-source")).color(Color32::from_rgb(255, 200, 90)));
+        let label = self.tr("pot-resize");
+        if self.has_mip_edits() {
+            ui.add_enabled(false, Checkbox::new(&mut false, label))
+                .on_disabled_hover_text(self.tr("pot-resize-edited"));
+            return;
+        }
+        ui.checkbox(&mut self.pot.enabled, label)
+            .on_hover_text(self.tr("pot-resize-hint"));
+        if !self.pot.enabled {
+            return;
+        }
+
+        let full_width = ui.available_width();
+        let targets = [
+            (PotTarget::Larger, self.tr("pot-target-larger")),
+            (PotTarget::Smaller, self.tr("pot-target-smaller")),
+        ];
+        let explicit_label = self.tr("pot-target-explicit");
+        let methods = PotMethod::ALL.map(|m| (m, self.tr(method_key(m))));
+        let s = &mut self.pot.settings;
+
+        // ------- Целевой размер -------
+        let selected = match s.target {
+            PotTarget::Explicit(..) => explicit_label.clone(),
+            t => targets
+                .iter()
+                .find(|(c, _)| *c == t)
+                .map(|(_, n)| n.clone())
+                .unwrap_or_default(),
+        };
+        ComboBox::from_id_salt("pot_target")
+            .width(full_width)
+            .selected_text(selected)
+            .show_ui(ui, |ui| {
+                for (t, name) in &targets {
+                    ui.selectable_value(&mut s.target, *t, name);
+                }
+                let explicit = matches!(s.target, PotTarget::Explicit(..));
+                if ui.selectable_label(explicit, &explicit_label).clicked() && !explicit {
+                    let (tw, th) = s.dims(w, h);
+                    s.target = PotTarget::Explicit(tw, th);
+                }
+            });
+        if let PotTarget::Explicit(tw, th) = &mut s.target {
+            ui.horizontal(|ui| {
+                for (salt, side) in [("pot_w", tw), ("pot_h", th)] {
+                    ComboBox::from_id_salt(salt)
+                        .width(60.0)
+                        .selected_text(side.to_string())
+                        .show_ui(ui, |ui| {
+                            for cand in POT_SIDES {
+                                ui.selectable_value(&mut *side, cand, cand.to_string());
+                            }
+                        });
+                }
+            });
+        }
+
+        // ------- Фильтр / заполнение -------
+        let selected = methods
+            .iter()
+            .find(|(m, _)| *m == s.method)
+            .map(|(_, n)| n.clone())
+            .unwrap_or_default();
+        ComboBox::from_id_salt("pot_method")
+            .width(full_width)
+            .selected_text(selected)
+            .show_ui(ui, |ui| {
+                for (m, name) in &methods {
+                    ui.selectable_value(&mut s.method, *m, name);
+                }
+            });
+
+        let (tw, th) = s.dims(w, h);
+        ui.horizontal(|ui| {
+            ui.label(RichText::new(format!("{w}×{h} → {tw}×{th}")).monospace());
+            if ui
+                .button(self.tr("pot-preview"))
+                .on_hover_text(self.tr("pot-preview-hint"))
+                .on_hover_cursor(CursorIcon::PointingHand)
+                .clicked()
+            {
+                self.pot.preview_open = true;
+            }
+        });
+    }
+
+    /// Окно «до/после»; пересчитывается при смене файла или настроек.
+    pub(crate) fn draw_pot_preview_window(&mut self, ctx: &Context) {
+        if !self.pot.preview_open {
+            return;
+        }
+        let key = (self.decode_gen, self.mip_edit_gen, self.pot.settings);
+        if self.pot.preview.as_ref().map(|p| p.key) != Some(key) {
+            self.pot.preview = self.build_pot_preview(ctx);
+        }
+
+        let mut open = self.pot.preview_open;
+        Window::new(self.tr("pot-preview"))
+            .id(Id::new("pot_preview_window"))
+            .open(&mut open)
+            .resizable(true)
+            .default_size([640.0, 360.0])
+            .show(ctx, |ui| {
+                let Some(p) = self.pot.preview.as_ref() else {
+                    ui.label(self.tr("pot-preview-none"));
+                    return;
+                };
+                let half = (ui.available_width() - ui.spacing().item_spacing.x) / 2.0;
+                ui.horizontal_top(|ui| {
+                    for (label, tex) in [(self.tr("pot-before"), &p.before), (self.tr("pot-after"), &p.after)] {
+                        ui.vertical(|ui| {
+                            let [tw, th] = tex.size();
+                            ui.label(RichText::new(format!("{label}: {tw}×{th}")).strong());
+                            let scale = (half / tw as f32).min(half / th as f32).min(4.0);
+                            ui.image((tex.id(), vec2(tw as f32, th as f32) * scale));
+                        });
+                    }
+                });
+            });
+        self.pot.preview_open = open;
+        if !open {
+            self.pot.preview = None;
+        }
+    }
+
+    fn build_pot_preview(&self, ctx: &Context) -> Option<PotPreview> {
+        let base = self.mip_images.first()?.as_ref()?;
+        let after = self.pot.settings.apply(base)?;
+        let options = self.texture_options();
+        let load = |name: &str, img: &RgbaImage| -> TextureHandle {
+            let (w, h) = img.dimensions();
+            ctx.load_texture(name, ColorImage::from_rgba_unmultiplied([w as usize, h as usize], img.as_raw()), options)
+        };
+        Some(PotPreview {
+            key: (self.decode_gen, self.mip_edit_gen, self.pot.settings),
+            before: load("pot_before", base),
+            after: load("pot_after", &after),
+        })
+    }
+}
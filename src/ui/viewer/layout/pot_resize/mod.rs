@@ -0,0 +1,2 @@
+mod draw_pot_resize;
+pub mod pot_resize_state;
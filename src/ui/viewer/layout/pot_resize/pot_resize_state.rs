@@ -0,0 +1,21 @@
+use crate::export::pot_resize::PotResize;
+use eframe::egui::TextureHandle;
+
+/// Для какого файла, правок и настроек построено превью: `(decode_gen, mip_edit_gen, settings)`.
+pub type PotPreviewKey = (u64, u64, PotResize);
+
+/// Текстуры «до/после» для окна превью.
+pub struct PotPreview {
+    pub key: PotPreviewKey,
+    pub before: TextureHandle,
+    pub after: TextureHandle,
+}
+
+/// Приведение к степени двойки при экспорте в BLP (только для NPOT-исходников).
+#[derive(Default)]
+pub struct PotResizeState {
+    pub enabled: bool,
+    pub settings: PotResize,
+    pub preview_open: bool,
+    pub preview: Option<PotPreview>,
+}
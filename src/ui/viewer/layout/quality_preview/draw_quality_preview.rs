@@ -0,0 +1,45 @@
+use crate::app::app::App;
+use crate::ui::viewer::layout::folder::human_size::human_size;
+use eframe::egui::{CursorIcon, RichText, Spinner, Ui};
+
+impl App {
+    /// Переключатель превью качества + оценка размера `.blp` и PSNR.
+    pub(crate) fn draw_quality_preview(&mut self, ui: &mut Ui) {
+        let label = self.tr("quality-preview");
+        if ui
+            .checkbox(&mut self.quality_preview.enabled, label)
+            .on_hover_text(self.tr("quality-preview-hint"))
+            .on_hover_cursor(CursorIcon::PointingHand)
+            .changed()
+        {
+            if !self.quality_preview.enabled {
+                self.quality_preview.reset();
+            }
+            self.load_mip_textures(ui.ctx());
+        }
+
+        if !self.quality_preview.enabled {
+            return;
+        }
+
+        ui.horizontal(|ui| {
+            match &self.quality_preview.result {
+                Some(r) => {
+                    let psnr = match r.psnr {
+                        Some(p) if p.is_finite() => format!("{p:.1} dB"),
+                        Some(_) => "∞".to_owned(),
+                        None => "—".to_owned(),
+                    };
+                    ui.label(RichText::new(format!("≈ {} · PSNR {psnr}", human_size(r.bytes as u64))).monospace())
+                        .on_hover_text(format!("{} {}\n{} B", self.tr("blp-quality"), r.key.0, r.bytes));
+                }
+                None => {
+                    ui.label(self.tr("quality-preview-encoding"));
+                }
+            }
+            if self.quality_preview.busy() {
+                ui.add(Spinner::new());
+            }
+        });
+    }
+}
@@ -0,0 +1,2 @@
+mod draw_quality_preview;
+pub mod quality_preview_state;
@@ -0,0 +1,129 @@
+use crate::app::app::App;
+use crate::decode::any_image_mips::any_image_mips;
+use crate::error::UiError;
+use crate::export::export_blp::encode_blp_bytes;
+use crate::metrics::image_diff::{mse, psnr};
+use blp::{AnyImage, BlpError, RgbaImage};
+use eframe::egui::Context;
+use std::sync::mpsc::{self, Receiver, TryRecvError};
+use std::thread;
+
+/// Что кодируем: качество + маска мипов (как при сохранении).
+pub type QualityKey = (u8, [bool; 16]);
+
+/// Результат фонового перекодирования в BLP.
+pub struct QualityPreviewResult {
+    pub key: QualityKey,
+    /// Размер будущего `.blp` в байтах.
+    pub bytes: usize,
+    /// PSNR первого записанного уровня против исходника.
+    pub psnr: Option<f64>,
+    /// Уровни после кодирования и обратного декодирования (с артефактами).
+    pub mips: Vec<Option<RgbaImage>>,
+}
+
+/// Живое превью JPEG-качества: пока включено, вьювер показывает перекодированные уровни.
+#[derive(Default)]
+pub struct QualityPreview {
+    pub enabled: bool,
+    pub result: Option<QualityPreviewResult>,
+    pub rx: Option<Receiver<Result<QualityPreviewResult, BlpError>>>,
+}
+
+impl QualityPreview {
+    /// Забыть результат и незавершённую задачу (новый файл).
+    pub fn reset(&mut self) {
+        self.result = None;
+        self.rx = None;
+    }
+
+    #[inline]
+    pub fn busy(&self) -> bool {
+        self.rx.is_some()
+    }
+}
+
+fn encode_preview(mips: Vec<Option<RgbaImage>>, key: QualityKey) -> Result<QualityPreviewResult, BlpError> {
+    let (quality, mask) = key;
+    let bytes = encode_blp_bytes(&mips, quality, &mask)?;
+    let decoded = AnyImage::from_buffer(&bytes)?;
+    let out = any_image_mips(&decoded)?;
+
+    let psnr = mips
+        .iter()
+        .zip(&out)
+        .zip(mask)
+        .find_map(|((src, dst), on)| match (src, dst) {
+            (Some(src), Some(dst)) if on => mse(src, dst),
+            _ => None,
+        })
+        .map(psnr);
+
+    Ok(QualityPreviewResult { key, bytes: bytes.len(), psnr, mips: out })
+}
+
+impl App {
+    /// Уровни, которые сейчас показываются: перекодированные в режиме превью, иначе исходные.
+    pub(crate) fn display_mips(&self) -> &[Option<RgbaImage>] {
+        match &self.quality_preview.result {
+            Some(r) if self.quality_preview.enabled => &r.mips,
+            _ => self.source_mips(),
+        }
+    }
+
+    /// Качество показанного превью (для подписи на холсте).
+    pub(crate) fn preview_quality(&self) -> Option<u8> {
+        let qp = &self.quality_preview;
+        qp.result
+            .as_ref()
+            .filter(|_| qp.enabled)
+            .map(|r| r.key.0)
+    }
+
+    /// Забирает готовый результат и, если слайдер/маска ушли вперёд, запускает новое кодирование.
+    /// Одновременно работает не больше одной задачи — промежуточные значения слайдера пропускаются.
+    pub(crate) fn poll_quality_preview(&mut self, ctx: &Context) {
+        if !self.quality_preview.enabled || self.loading || self.image.is_none() {
+            return;
+        }
+
+        if let Some(rx) = self.quality_preview.rx.take() {
+            match rx.try_recv() {
+                Ok(Ok(result)) => {
+                    self.quality_preview.result = Some(result);
+                    self.load_mip_textures(ctx);
+                }
+                Ok(Err(e)) => {
+                    self.quality_preview.enabled = false;
+                    self.error = Some(UiError::new("error-quality-preview").push_blp(e));
+                    self.load_mip_textures(ctx);
+                    return;
+                }
+                Err(TryRecvError::Empty) => {
+                    self.quality_preview.rx = Some(rx);
+                    ctx.request_repaint();
+                    return;
+                }
+                Err(TryRecvError::Disconnected) => {}
+            }
+        }
+
+        let key = (self.export_quality, self.mip_visible);
+        let fresh = self
+            .quality_preview
+            .result
+            .as_ref()
+            .is_some_and(|r| r.key == key);
+        if fresh || !self.source_mips().first().is_some_and(Option::is_some) {
+            return;
+        }
+
+        let (tx, rx) = mpsc::sync_channel(1);
+        self.quality_preview.rx = Some(rx);
+        let mips = self.source_mips().to_vec();
+        thread::spawn(move || {
+            let _ = tx.send(encode_preview(mips, key));
+        });
+        ctx.request_repaint();
+    }
+}
@@ -0,0 +1,98 @@
+use crate::app::app::App;
+use crate::paint_bg_maze::paint_maze;
+use eframe::egui::{Color32, ColorImage, Context, Painter, Rect, TextureOptions, pos2};
+use serde::{Deserialize, Serialize};
+
+const APP: &str = env!("CARGO_PKG_NAME");
+const CFG: Option<&str> = Some(stringify!(BackdropPrefs));
+
+/// Подложка под прозрачными пикселями.
+#[derive(Default, Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum BackdropKind {
+    #[default]
+    Checker,
+    Black,
+    White,
+    Magenta,
+    Custom,
+    Maze,
+}
+
+impl BackdropKind {
+    pub const ALL: [BackdropKind; 6] = [BackdropKind::Checker, BackdropKind::Black, BackdropKind::White, BackdropKind::Magenta, BackdropKind::Custom, BackdropKind::Maze];
+
+    pub fn key(self) -> &'static str {
+        match self {
+            BackdropKind::Checker => "backdrop-checker",
+            BackdropKind::Black => "backdrop-black",
+            BackdropKind::White => "backdrop-white",
+            BackdropKind::Magenta => "backdrop-magenta",
+            BackdropKind::Custom => "backdrop-custom",
+            BackdropKind::Maze => "backdrop-maze",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct BackdropPrefs {
+    pub kind: BackdropKind,
+    /// Размер клетки шахматки в экранных точках.
+    pub checker_size: u8,
+    pub custom: [u8; 3],
+}
+
+impl Default for BackdropPrefs {
+    fn default() -> Self {
+        Self { kind: BackdropKind::Checker, checker_size: 8, custom: [64, 64, 64] }
+    }
+}
+
+pub fn backdrop_load() -> BackdropPrefs {
+    let mut p = confy::load::<BackdropPrefs>(APP, CFG).unwrap_or_default();
+    p.checker_size = p.checker_size.clamp(2, 64);
+    p
+}
+
+pub fn backdrop_save(val: BackdropPrefs) -> Result<(), confy::ConfyError> {
+    confy::store(APP, CFG, val)
+}
+
+impl App {
+    /// Рисует подложку под мипом. `visible` — видимая часть `rect` (для лабиринта).
+    pub(crate) fn paint_backdrop(&self, painter: &Painter, rect: Rect, visible: Rect) {
+        let bd = self.view.backdrop;
+        let solid = |c: Color32| {
+            painter.rect_filled(rect, 0.0, c);
+        };
+        match bd.kind {
+            BackdropKind::Checker => {
+                let Some(tex) = self.checker_tex.as_ref() else {
+                    return;
+                };
+                // 2×2 текстура с повтором: одна клетка = checker_size точек
+                let cell = bd.checker_size.max(1) as f32 * 2.0;
+                let uv = Rect::from_min_max(pos2(0.0, 0.0), pos2(rect.width() / cell, rect.height() / cell));
+                painter.image(tex.id(), rect, uv, Color32::WHITE);
+            }
+            BackdropKind::Black => solid(Color32::BLACK),
+            BackdropKind::White => solid(Color32::WHITE),
+            BackdropKind::Magenta => solid(Color32::from_rgb(255, 0, 255)),
+            BackdropKind::Custom => solid(Color32::from_rgb(bd.custom[0], bd.custom[1], bd.custom[2])),
+            BackdropKind::Maze => {
+                let visible = rect.intersect(visible);
+                painter.rect_filled(visible, 0.0, Color32::from_rgb(28, 120, 120));
+                paint_maze(&painter.with_clip_rect(visible), visible, 0x5EED);
+            }
+        }
+    }
+
+    /// Текстура шахматки (создаётся один раз).
+    pub(crate) fn ensure_checker_tex(&mut self, ctx: &Context) {
+        if self.checker_tex.is_some() {
+            return;
+        }
+        let (a, b) = (Color32::from_gray(204), Color32::from_gray(153));
+        let ci = ColorImage::new([2, 2], vec![a, b, b, a]);
+        self.checker_tex = Some(ctx.load_texture("backdrop_checker", ci, TextureOptions::NEAREST_REPEAT));
+    }
+}
@@ -0,0 +1,55 @@
+/// Какие каналы показывать.
+#[derive(Default, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ChannelView {
+    /// Как есть, с прозрачностью.
+    #[default]
+    Rgba,
+    /// RGB без учёта альфы.
+    Rgb,
+    /// Отдельный канал оттенками серого.
+    R,
+    G,
+    B,
+    /// Только альфа оттенками серого.
+    Alpha,
+}
+
+impl ChannelView {
+    pub const ALL: [ChannelView; 6] = [ChannelView::Rgba, ChannelView::Rgb, ChannelView::R, ChannelView::G, ChannelView::B, ChannelView::Alpha];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            ChannelView::Rgba => "RGBA",
+            ChannelView::Rgb => "RGB",
+            ChannelView::R => "R",
+            ChannelView::G => "G",
+            ChannelView::B => "B",
+            ChannelView::Alpha => "A",
+        }
+    }
+
+    /// Ключ локализованной подсказки.
+    pub fn hint_key(self) -> &'static str {
+        match self {
+            ChannelView::Rgba => "channel-rgba-hint",
+            ChannelView::Rgb => "channel-rgb-hint",
+            ChannelView::R => "channel-r-hint",
+            ChannelView::G => "channel-g-hint",
+            ChannelView::B => "channel-b-hint",
+            ChannelView::Alpha => "channel-a-hint",
+        }
+    }
+
+    /// RGBA-буфер для текстуры. Для `Rgba` отдаёт исходник без копии.
+    pub fn apply<'a>(self, rgba: &'a [u8]) -> std::borrow::Cow<'a, [u8]> {
+        let map = |f: fn(&[u8]) -> [u8; 4]| -> Vec<u8> { rgba.chunks_exact(4).flat_map(f).collect() };
+        match self {
+            ChannelView::Rgba => std::borrow::Cow::Borrowed(rgba),
+            ChannelView::Rgb => map(|p| [p[0], p[1], p[2], 255]).into(),
+            ChannelView::R => map(|p| [p[0], p[0], p[0], 255]).into(),
+            ChannelView::G => map(|p| [p[1], p[1], p[1], 255]).into(),
+            ChannelView::B => map(|p| [p[2], p[2], p[2], 255]).into(),
+            ChannelView::Alpha => map(|p| [p[3], p[3], p[3], 255]).into(),
+        }
+    }
+}
@@ -0,0 +1,151 @@
+use crate::app::app::App;
+use crate::ui::viewer::layout::view::mip_layout::{GAP, PAD};
+use crate::ui::viewer::layout::view::view_state::ViewZoom;
+use crate::ui::viewer::layout::view::pixel_sample::PixelSample;
+use crate::ui::viewer::layout::compare::compare_state::CompareMode;
+use eframe::egui::{Align2, Color32, FontId, Rect, RichText, Sense, Stroke, Ui, pos2, vec2};
+use std::time::Duration;
+
+impl App {
+    /// Холст с мипами: колесо — зум вокруг курсора, Shift+колесо — прокрутка, перетаскивание — сдвиг.
+    pub(crate) fn draw_mip_canvas(&mut self, ui: &mut Ui) {
+        let (panel, resp) = ui.allocate_exact_size(ui.available_size(), Sense::click_and_drag());
+        self.ensure_checker_tex(ui.ctx());
+
+        // --- ввод ---
+        if resp.dragged() {
+            let d = resp.drag_delta();
+            self.view.pan += match self.view.zoom {
+                ViewZoom::Fit => vec2(0.0, d.y),
+                ViewZoom::Scale(_) => d,
+            };
+        }
+        if let Some(cursor) = resp.hover_pos() {
+            let (scroll, shift, pinch) = ui.input(|i| (i.smooth_scroll_delta, i.modifiers.shift, i.zoom_delta()));
+            let origin = self.mip_origin(panel);
+            let k = if shift {
+                // egui может перенести Shift+колесо в горизонталь — берём обе оси
+                self.view.pan.y += scroll.x + scroll.y;
+                pinch
+            } else {
+                pinch * (scroll.y * 0.002).exp()
+            };
+            if k != 1.0 {
+                if self.view.zoom == ViewZoom::Fit {
+                    // из Fit переходим в масштаб первого видимого мипа, прокрутку сохраняем
+                    let z = self
+                        .mip_layout(panel)
+                        .first()
+                        .map_or(1.0, |m| m.scale);
+                    let pan_y = self.view.pan.y;
+                    self.view.set_zoom(ViewZoom::Scale(z));
+                    self.view.pan.y = pan_y;
+                }
+                self.view.zoom_at(origin, cursor, k);
+            }
+        }
+
+        // В Fit не даём уехать за содержимое
+        if self.view.zoom == ViewZoom::Fit {
+            let content_h = self
+                .mip_layout(panel)
+                .last()
+                .map_or(0.0, |m| m.rect.bottom() - self.view.pan.y - panel.top() + PAD);
+            let min_pan = (panel.height() - content_h - GAP).min(0.0);
+            self.view.pan.y = self.view.pan.y.clamp(min_pan, 0.0);
+        }
+
+        self.draw_mip_canvas_paint(ui, panel);
+
+        // --- инспектор пикселя ---
+        if let Some(sample) = resp.hover_pos().and_then(|p| self.sample_at(panel, p)) {
+            if resp.clicked() {
+                self.pinned.push(sample);
+            }
+            if !resp.dragged() {
+                resp.on_hover_ui_at_pointer(|ui| pixel_sample_ui(ui, &sample));
+            }
+        }
+    }
+
+    fn draw_mip_canvas_paint(&self, ui: &Ui, panel: Rect) {
+        let painter = ui.painter_at(panel);
+        let label_color = ui.visuals().widgets.inactive.fg_stroke.color;
+        let uv = Rect::from_min_max(pos2(0.0, 0.0), pos2(1.0, 1.0));
+
+        let mode = self.compare_mode();
+        let cmp = self.compare.as_ref();
+        // Flicker: полсекунды A, полсекунды B
+        let flicker_b = mode == Some(CompareMode::Flicker) && {
+            ui.ctx()
+                .request_repaint_after(Duration::from_millis(100));
+            (ui.input(|i| i.time) * 2.0) as i64 % 2 == 1
+        };
+
+        for m in self.mip_layout(panel) {
+            if !m.rect.intersects(panel) {
+                continue;
+            }
+            let Some(tex) = self.mip_textures[m.index].as_ref() else {
+                continue;
+            };
+            let b_tex = cmp.and_then(|c| c.textures[m.index].as_ref());
+            self.paint_backdrop(&painter, m.rect, panel);
+
+            let mut right = m.rect.right();
+            match (mode, b_tex) {
+                (Some(CompareMode::SideBySide), Some(b)) => {
+                    painter.image(tex.id(), m.rect, uv, Color32::WHITE);
+                    let b_rect = Rect::from_min_size(pos2(m.rect.right() + GAP, m.rect.top()), b.size_vec2() * m.scale);
+                    self.paint_backdrop(&painter, b_rect, panel);
+                    painter.image(b.id(), b_rect, uv, Color32::WHITE);
+                    right = b_rect.right();
+                }
+                (Some(CompareMode::Swipe), Some(b)) => {
+                    painter.image(tex.id(), m.rect, uv, Color32::WHITE);
+                    let split = m.rect.left() + m.rect.width() * cmp.map_or(0.5, |c| c.swipe);
+                    let clip = Rect::from_min_max(pos2(split, m.rect.top()), m.rect.max).intersect(panel);
+                    painter
+                        .with_clip_rect(clip)
+                        .image(b.id(), m.rect, uv, Color32::WHITE);
+                    painter.vline(split, m.rect.y_range(), Stroke::new(1.5, Color32::from_rgb(255, 200, 0)));
+                }
+                (Some(CompareMode::Flicker), Some(b)) if flicker_b => {
+                    painter.image(b.id(), m.rect, uv, Color32::WHITE);
+                }
+                (Some(CompareMode::Heatmap), _) => {
+                    let heat = cmp.and_then(|c| c.heatmaps[m.index].as_ref());
+                    painter.image(heat.unwrap_or(tex).id(), m.rect, uv, Color32::WHITE);
+                }
+                _ => {
+                    painter.image(tex.id(), m.rect, uv, Color32::WHITE);
+                }
+            }
+
+            let [w, h] = tex.size();
+            let mut title = format!("#{:02} {w}×{h}", m.index);
+            if let Some(q) = self.preview_quality() {
+                title.push_str(&format!(" q{q}"));
+            }
+            if flicker_b {
+                title.push_str(" B");
+            }
+            let (pos, align) = match self.view.zoom {
+                ViewZoom::Fit => (pos2(panel.right() - PAD, m.rect.top()), Align2::RIGHT_TOP),
+                ViewZoom::Scale(_) => (pos2(right + PAD, m.rect.top()), Align2::LEFT_TOP),
+            };
+            painter.text(pos, align, title, FontId::monospace(12.0), label_color);
+        }
+    }
+}
+
+/// Всплывашка инспектора: плашка цвета + текстовое описание текселя.
+fn pixel_sample_ui(ui: &mut Ui, s: &PixelSample) {
+    ui.horizontal(|ui| {
+        let [r, g, b, a] = s.rgba;
+        let (rect, _) = ui.allocate_exact_size(vec2(16.0, 16.0), Sense::hover());
+        ui.painter()
+            .rect_filled(rect, 2.0, Color32::from_rgba_unmultiplied(r, g, b, a));
+        ui.label(RichText::new(s.to_text()).monospace());
+    });
+}
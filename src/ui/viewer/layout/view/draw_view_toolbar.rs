@@ -0,0 +1,101 @@
+use crate::app::app::App;
+use crate::ui::viewer::layout::view::backdrop::{BackdropKind, backdrop_save};
+use crate::ui::viewer::layout::view::channel_view::ChannelView;
+use crate::ui::viewer::layout::view::view_state::ViewZoom;
+use eframe::egui::{ComboBox, Context, CursorIcon, DragValue, RichText, TopBottomPanel};
+
+impl App {
+    /// Панель над мипами: пресеты масштаба и фильтрация.
+    pub(crate) fn draw_view_toolbar(&mut self, ctx: &Context) {
+        TopBottomPanel::top("view_toolbar")
+            .show_separator_line(false)
+            .show(ctx, |ui| {
+                ui.horizontal_wrapped(|ui| {
+                    ui.add_enabled_ui(!self.loading, |ui| {
+                        let presets = [("zoom-fit", ViewZoom::Fit), ("zoom-100", ViewZoom::Scale(1.0)), ("zoom-200", ViewZoom::Scale(2.0)), ("zoom-400", ViewZoom::Scale(4.0))];
+                        for (key, zoom) in presets {
+                            if ui
+                                .selectable_label(self.view.zoom == zoom, self.tr(key))
+                                .on_hover_cursor(CursorIcon::PointingHand)
+                                .clicked()
+                            {
+                                self.view.set_zoom(zoom);
+                            }
+                        }
+
+                        if let ViewZoom::Scale(z) = self.view.zoom {
+                            ui.label(RichText::new(format!("{:.0}%", z * 100.0)).monospace());
+                        }
+
+                        ui.separator();
+
+                        let nearest_label = self.tr("zoom-nearest");
+                        if ui
+                            .checkbox(&mut self.view.nearest, nearest_label)
+                            .on_hover_text(self.tr("zoom-nearest-hint"))
+                            .changed()
+                        {
+                            self.load_mip_textures(ctx);
+                        }
+
+                        ui.separator();
+
+                        for ch in ChannelView::ALL {
+                            if ui
+                                .selectable_label(self.view.channel == ch, RichText::new(ch.label()).monospace())
+                                .on_hover_text(self.tr(ch.hint_key()))
+                                .on_hover_cursor(CursorIcon::PointingHand)
+                                .clicked()
+                                && self.view.channel != ch
+                            {
+                                self.view.channel = ch;
+                                self.load_mip_textures(ctx);
+                            }
+                        }
+
+                        ui.separator();
+
+                        // Подложка
+                        let before = self.view.backdrop;
+                        let names = BackdropKind::ALL.map(|k| (k, self.tr(k.key())));
+                        let hint = self.tr("backdrop-hint");
+                        let size_hint = self.tr("backdrop-checker-size-hint");
+                        let bd = &mut self.view.backdrop;
+                        let selected = names
+                            .iter()
+                            .find(|(k, _)| *k == bd.kind)
+                            .map(|(_, n)| n.clone())
+                            .unwrap_or_default();
+                        let ir = ComboBox::from_id_salt("view_backdrop")
+                            .selected_text(selected)
+                            .show_ui(ui, |ui| {
+                                for (kind, name) in &names {
+                                    ui.selectable_value(&mut bd.kind, *kind, name)
+                                        .on_hover_cursor(CursorIcon::PointingHand);
+                                }
+                            });
+                        ir.response
+                            .on_hover_text(hint)
+                            .on_hover_cursor(CursorIcon::PointingHand);
+                        match bd.kind {
+                            BackdropKind::Checker => {
+                                ui.add(DragValue::new(&mut bd.checker_size).range(2..=64).suffix(" px"))
+                                    .on_hover_text(size_hint);
+                            }
+                            BackdropKind::Custom => {
+                                ui.color_edit_button_srgb(&mut bd.custom);
+                            }
+                            _ => {}
+                        }
+                        if self.view.backdrop != before {
+                            let _ = backdrop_save(self.view.backdrop);
+                        }
+
+                        ui.separator();
+
+                        self.draw_compare_toolbar(ui);
+                    });
+                });
+            });
+    }
+}
@@ -0,0 +1,64 @@
+use crate::app::app::App;
+use crate::ui::viewer::layout::compare::compare_state::CompareMode;
+use crate::ui::viewer::layout::view::view_state::ViewZoom;
+use eframe::egui::{Pos2, Rect, vec2};
+
+/// Отступ содержимого от краёв панели.
+pub const PAD: f32 = 8.0;
+/// Место под подпись `#NN w×h` справа в режиме Fit.
+pub const LABEL_W: f32 = 110.0;
+/// Зазор между мипами (экранные точки в Fit, точки содержимого в Scale).
+pub const GAP: f32 = 8.0;
+
+/// Где рисуется мип: индекс уровня, экранный прямоугольник и масштаб.
+#[derive(Clone, Copy, Debug)]
+pub struct MipPlacement {
+    pub index: usize,
+    pub rect: Rect,
+    pub scale: f32,
+}
+
+impl App {
+    /// Левый верхний угол содержимого без учёта сдвига.
+    #[inline]
+    pub(crate) fn mip_origin(&self, panel: Rect) -> Pos2 {
+        panel.min + vec2(PAD, PAD)
+    }
+
+    /// Раскладка видимых мипов сверху вниз в порядке индексов.
+    pub(crate) fn mip_layout(&self, panel: Rect) -> Vec<MipPlacement> {
+        let origin = self.mip_origin(panel) + self.view.pan;
+        // Бок о бок с B — в Fit каждому достаётся половина ширины
+        let cols = if self.compare_mode() == Some(CompareMode::SideBySide) { 2.0 } else { 1.0 };
+        let fit_w = ((panel.width() - 2.0 * PAD - LABEL_W - GAP * (cols - 1.0)) / cols).max(1.0);
+
+        let mut out = Vec::new();
+        let mut y = 0.0;
+        for i in 0..16 {
+            if !self.mip_visible[i] {
+                continue;
+            }
+            let Some(tex) = self.mip_textures.get(i).and_then(|t| t.as_ref()) else {
+                continue;
+            };
+            let size = tex.size_vec2();
+            if size.x <= 0.0 || size.y <= 0.0 {
+                continue;
+            }
+            match self.view.zoom {
+                ViewZoom::Fit => {
+                    let scale = (fit_w / size.x).min(1.0);
+                    let rect = Rect::from_min_size(origin + vec2(0.0, y), size * scale);
+                    y += rect.height() + GAP;
+                    out.push(MipPlacement { index: i, rect, scale });
+                }
+                ViewZoom::Scale(z) => {
+                    let rect = Rect::from_min_size(origin + vec2(0.0, y) * z, size * z);
+                    y += size.y + GAP;
+                    out.push(MipPlacement { index: i, rect, scale: z });
+                }
+            }
+        }
+        out
+    }
+}
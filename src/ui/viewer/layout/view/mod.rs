@@ -0,0 +1,7 @@
+pub mod backdrop;
+pub mod channel_view;
+mod draw_mip_canvas;
+mod draw_view_toolbar;
+pub mod mip_layout;
+pub mod pixel_sample;
+pub mod view_state;
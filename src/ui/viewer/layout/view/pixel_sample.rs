@@ -0,0 +1,62 @@
+use crate::app::app::App;
+use eframe::egui::{Pos2, Rect};
+
+/// Значение одного текселя под курсором (или закреплённое кликом).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PixelSample {
+    pub mip: usize,
+    pub x: u32,
+    pub y: u32,
+    pub rgba: [u8; 4],
+    /// Только палитровые BLP.
+    pub palette_index: Option<u8>,
+    /// Только JPEG BLP: номер блока 8×8 построчно.
+    pub jpeg_block: Option<u32>,
+}
+
+impl PixelSample {
+    /// `#RRGGBBAA`
+    pub fn hex(&self) -> String {
+        let [r, g, b, a] = self.rgba;
+        format!("#{r:02X}{g:02X}{b:02X}{a:02X}")
+    }
+
+    /// Одна строка для копирования: `#00 (x, y)  RGBA(r, g, b, a)  #RRGGBBAA  idx N  block N`.
+    pub fn to_text(self) -> String {
+        let [r, g, b, a] = self.rgba;
+        let mut s = format!("#{:02} ({}, {})  RGBA({r}, {g}, {b}, {a})  {}", self.mip, self.x, self.y, self.hex());
+        if let Some(i) = self.palette_index {
+            s.push_str(&format!("  idx {i}"));
+        }
+        if let Some(b) = self.jpeg_block {
+            s.push_str(&format!("  block {b}"));
+        }
+        s
+    }
+}
+
+impl App {
+    /// Тексель под экранной точкой `pos` в координатах своего мипа (показанные пиксели, без фильтра каналов).
+    pub(crate) fn sample_at(&self, panel: Rect, pos: Pos2) -> Option<PixelSample> {
+        let m = self
+            .mip_layout(panel)
+            .into_iter()
+            .find(|m| m.rect.contains(pos))?;
+        let img = self.display_mips().get(m.index)?.as_ref()?;
+        let (w, h) = img.dimensions();
+
+        let local = (pos - m.rect.min) / m.scale;
+        let x = (local.x.floor().max(0.0) as u32).min(w.saturating_sub(1));
+        let y = (local.y.floor().max(0.0) as u32).min(h.saturating_sub(1));
+
+        let info = self.blp_info.as_ref();
+        Some(PixelSample {
+            mip: m.index,
+            x,
+            y,
+            rgba: img.get_pixel(x, y).0,
+            palette_index: info.and_then(|i| i.palette_index(m.index, x, y)),
+            jpeg_block: info.and_then(|i| i.jpeg_block(m.index, x, y)),
+        })
+    }
+}
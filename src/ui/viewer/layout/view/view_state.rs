@@ -0,0 +1,58 @@
+use crate::ui::viewer::layout::view::backdrop::BackdropPrefs;
+use crate::ui::viewer::layout::view::channel_view::ChannelView;
+use eframe::egui::{Pos2, Vec2};
+
+pub const ZOOM_MIN: f32 = 0.05;
+pub const ZOOM_MAX: f32 = 64.0;
+
+/// Масштаб просмотра мипов.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ViewZoom {
+    /// Каждый мип ужимается по ширине панели (крупнее 1:1 не растягивается).
+    Fit,
+    /// Единый масштаб для всех мипов, 1.0 = 100%.
+    Scale(f32),
+}
+
+/// Состояние вьювера: масштаб, сдвиг и фильтрация. Переживает смену файла.
+#[derive(Clone, Copy, Debug)]
+pub struct ViewState {
+    pub zoom: ViewZoom,
+    /// Сдвиг содержимого в экранных точках (в режиме Fit — только по вертикали).
+    pub pan: Vec2,
+    /// Nearest-neighbour вместо билинейной фильтрации.
+    pub nearest: bool,
+    /// Изоляция каналов для всех мипов.
+    pub channel: ChannelView,
+    /// Подложка под мипами (сохраняется в confy).
+    pub backdrop: BackdropPrefs,
+}
+
+impl Default for ViewState {
+    fn default() -> Self {
+        Self { zoom: ViewZoom::Fit, pan: Vec2::ZERO, nearest: false, channel: ChannelView::Rgba, backdrop: BackdropPrefs::default() }
+    }
+}
+
+impl ViewState {
+    /// Пресет масштаба: сбрасывает сдвиг.
+    pub fn set_zoom(&mut self, zoom: ViewZoom) {
+        self.zoom = match zoom {
+            ViewZoom::Scale(z) => ViewZoom::Scale(z.clamp(ZOOM_MIN, ZOOM_MAX)),
+            ViewZoom::Fit => ViewZoom::Fit,
+        };
+        self.pan = Vec2::ZERO;
+    }
+
+    /// Умножает масштаб на `k`, удерживая точку под курсором на месте.
+    /// `origin` — экранная точка, где лежит (0,0) содержимого без сдвига.
+    pub fn zoom_at(&mut self, origin: Pos2, cursor: Pos2, k: f32) {
+        let ViewZoom::Scale(z) = self.zoom else {
+            return;
+        };
+        let z1 = (z * k).clamp(ZOOM_MIN, ZOOM_MAX);
+        let q = (cursor - origin - self.pan) / z;
+        self.pan = cursor - origin - q * z1;
+        self.zoom = ViewZoom::Scale(z1);
+    }
+}